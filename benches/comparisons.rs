@@ -5,14 +5,14 @@ use criterion_cycles_per_byte::CyclesPerByte;
 use fath::*;
 use sleef::f32x::*;
 use sleef::Sleef;
-use std::simd::f32x8;
+use std::simd::{f32x8, u32x8, u64x4};
 
 fn cos_benchmarks(c: &mut Criterion<CyclesPerByte>) {
     c.bench_function("fath f32x8 cos precision: 0 range-reduced: true", |b| {
-        b.iter(|| unsafe { black_box(f32x8::splat(0.0_f32)).cos_fast_approx::<0>() })
+        b.iter(|| unsafe { black_box(f32x8::splat(0.0_f32)).cos_fast_approx::<P0>() })
     });
     c.bench_function("fath f32x8 cos precision: 3 range-reduced: true", |b| {
-        b.iter(|| unsafe { black_box(f32x8::splat(0.0_f32)).cos_fast_approx::<3>() })
+        b.iter(|| unsafe { black_box(f32x8::splat(0.0_f32)).cos_fast_approx::<P3>() })
     });
     c.bench_function("sleef f32x8 cos fast range-reduced: true", |b| {
         b.iter(|| cos_fast(black_box(f32x8::splat(0.0_f32))))
@@ -21,22 +21,28 @@ fn cos_benchmarks(c: &mut Criterion<CyclesPerByte>) {
 
 fn sin_benchmarks(c: &mut Criterion<CyclesPerByte>) {
     c.bench_function("fath f32x8 sin precision: 0 range-reduced: true", |b| {
-        b.iter(|| unsafe { black_box(f32x8::splat(0.0_f32)).sin_fast_approx::<0>() })
+        b.iter(|| unsafe { black_box(f32x8::splat(0.0_f32)).sin_fast_approx::<P0>() })
     });
     c.bench_function("fath f32x8 sin precision: 3 range-reduced: true", |b| {
-        b.iter(|| unsafe { black_box(f32x8::splat(0.0_f32)).sin_fast_approx::<3>() })
+        b.iter(|| unsafe { black_box(f32x8::splat(0.0_f32)).sin_fast_approx::<P3>() })
     });
     c.bench_function("sleef f32x8 sin fast range-reduced: true", |b| {
         b.iter(|| sin_fast(black_box(f32x8::splat(0.0_f32))))
     });
+    c.bench_function("fath f32x8 sin_wide precision: 3 cody-waite", |b| {
+        b.iter(|| unsafe { black_box(f32x8::splat(1000.0_f32)).sin_wide_fast_approx::<P3>() })
+    });
+    c.bench_function("fath f32x8 sin_wide precision: 3 payne-hanek", |b| {
+        b.iter(|| unsafe { black_box(f32x8::splat(1.0e30_f32)).sin_wide_fast_approx::<P3>() })
+    });
 }
 
 fn log2_benchmarks(c: &mut Criterion<CyclesPerByte>) {
     c.bench_function("fath f32x8 log2 precision: 0 range-reduced: true", |b| {
-        b.iter(|| unsafe { (black_box(f32x8::splat(0.0_f32))).log2_fast_approx::<0>() })
+        b.iter(|| unsafe { (black_box(f32x8::splat(0.0_f32))).log2_fast_approx::<P0>() })
     });
     c.bench_function("fath f32x8 log2 precision: 3 range-reduced: true", |b| {
-        b.iter(|| unsafe { (black_box(f32x8::splat(0.0_f32))).log2_fast_approx::<3>() })
+        b.iter(|| unsafe { (black_box(f32x8::splat(0.0_f32))).log2_fast_approx::<P3>() })
     });
     c.bench_function("sleef f32x8 log2 3.5 ULP range-reduced: true", |b| {
         b.iter(|| log2_u35(black_box(f32x8::splat(0.0_f32))))
@@ -45,16 +51,107 @@ fn log2_benchmarks(c: &mut Criterion<CyclesPerByte>) {
 
 fn ln_benchmarks(c: &mut Criterion<CyclesPerByte>) {
     c.bench_function("fath f32x8 ln precision: 0 range-reduced: true", |b| {
-        b.iter(|| unsafe { (black_box(f32x8::splat(0.0_f32))).ln_fast_approx::<0>() })
+        b.iter(|| unsafe { (black_box(f32x8::splat(0.0_f32))).ln_fast_approx::<P0>() })
     });
     c.bench_function("fath f32x8 ln precision: 3 range-reduced: true", |b| {
-        b.iter(|| unsafe { (black_box(f32x8::splat(0.0_f32))).ln_fast_approx::<3>() })
+        b.iter(|| unsafe { (black_box(f32x8::splat(0.0_f32))).ln_fast_approx::<P3>() })
     });
     c.bench_function("sleef f32x8 ln 3.5 ULP range-reduced: true", |b| {
         b.iter(|| (black_box(f32x8::splat(0.0_f32))).ln())
     });
 }
 
+fn ilog_var_base_benchmarks(c: &mut Criterion<CyclesPerByte>) {
+    // varied inputs, so the loop in std's ilog can't be predicted
+    let inputs: Vec<(u32, u32)> = (0..1024_u32)
+        .map(|i| {
+            (
+                i.wrapping_mul(2_654_435_761) | 1,
+                2 + i.wrapping_mul(40_503) % 100,
+            )
+        })
+        .collect();
+
+    c.bench_function("fath u32 ilog var base", |b| {
+        b.iter(|| {
+            for &(x, base) in black_box(&inputs) {
+                black_box(x.ilog_var_base(base));
+            }
+        })
+    });
+    let simd_inputs: Vec<(u32x8, u32x8)> = inputs
+        .chunks_exact(8)
+        .map(|chunk| {
+            (
+                u32x8::from_array(std::array::from_fn(|i| chunk[i].0)),
+                u32x8::from_array(std::array::from_fn(|i| chunk[i].1)),
+            )
+        })
+        .collect();
+
+    c.bench_function("fath u32x8 ilog var base", |b| {
+        b.iter(|| {
+            for &(x, base) in black_box(&simd_inputs) {
+                black_box(x.ilog_var_base(base));
+            }
+        })
+    });
+    c.bench_function("std u32 ilog var base", |b| {
+        b.iter(|| {
+            for &(x, base) in black_box(&inputs) {
+                black_box(x.ilog(base));
+            }
+        })
+    });
+}
+
+fn div_const_benchmarks(c: &mut Criterion<CyclesPerByte>) {
+    let inputs: Vec<u64x4> = (0..1024_u64)
+        .map(|i| {
+            u64x4::from_array(std::array::from_fn(|j| {
+                (i * 4 + j as u64) * 0x9e37_79b9_7f4a_7c15
+            }))
+        })
+        .collect();
+
+    c.bench_function("fath u64x4 div const", |b| {
+        b.iter(|| {
+            for &x in black_box(&inputs) {
+                black_box(x.div_const::<641>());
+            }
+        })
+    });
+    c.bench_function("std u64x4 div", |b| {
+        b.iter(|| {
+            for &x in black_box(&inputs) {
+                black_box(x / u64x4::splat(641));
+            }
+        })
+    });
+
+    // the divisor isn't known at compile time, so the compiler can't use a multiply for std
+    let inputs: Vec<u32x8> = (0..1024_u32)
+        .map(|i| u32x8::from_array(std::array::from_fn(|j| (i * 8 + j as u32) * 0x9e37_79b9)))
+        .collect();
+    let d = black_box(641_u32);
+    let divisor = FastDivisor::<u32>::new(d);
+
+    c.bench_function("fath u32x8 fast divisor", |b| {
+        b.iter(|| {
+            for &x in black_box(&inputs) {
+                black_box(x / divisor);
+            }
+        })
+    });
+    c.bench_function("std u32x8 runtime div", |b| {
+        b.iter(|| {
+            for &x in black_box(&inputs) {
+                black_box(x / u32x8::splat(d));
+            }
+        })
+    });
+}
+
 criterion_group! {
     name = cos;
     config = Criterion::default().sample_size(10000).with_measurement(CyclesPerByte);
@@ -75,4 +172,14 @@ criterion_group! {
     config = Criterion::default().sample_size(10000).with_measurement(CyclesPerByte);
     targets = ln_benchmarks
 }
-criterion_main!(cos, sin, log2, ln);
+criterion_group! {
+    name = ilog_var_base;
+    config = Criterion::default().sample_size(10000).with_measurement(CyclesPerByte);
+    targets = ilog_var_base_benchmarks
+}
+criterion_group! {
+    name = div_const;
+    config = Criterion::default().sample_size(10000).with_measurement(CyclesPerByte);
+    targets = div_const_benchmarks
+}
+criterion_main!(cos, sin, log2, ln, ilog_var_base, div_const);
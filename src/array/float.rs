@@ -0,0 +1,322 @@
+use crate::shared::float::{FastApproxFloat, FastApproxFloatChecked, Precision};
+use core::array;
+
+impl<T, const LANES: usize> FastApproxFloat for [T; LANES]
+where
+    T: FastApproxFloat<Scalar = T> + Copy,
+{
+    type Scalar = T;
+
+    #[inline(always)]
+    unsafe fn sin_fast_approx<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.sin_fast_approx::<P>())
+    }
+
+    #[inline(always)]
+    unsafe fn cos_fast_approx<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.cos_fast_approx::<P>())
+    }
+
+    #[inline(always)]
+    unsafe fn sincos_fast_approx<P: Precision<T>>(self) -> (Self, Self) {
+        let pairs = self.map(|x| x.sincos_fast_approx::<P>());
+        (pairs.map(|pair| pair.0), pairs.map(|pair| pair.1))
+    }
+
+    #[inline(always)]
+    unsafe fn sin_wide_fast_approx<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.sin_wide_fast_approx::<P>())
+    }
+
+    #[inline(always)]
+    unsafe fn cos_wide_fast_approx<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.cos_wide_fast_approx::<P>())
+    }
+
+    #[inline(always)]
+    unsafe fn sincos_wide_fast_approx<P: Precision<T>>(self) -> (Self, Self) {
+        let pairs = self.map(|x| x.sincos_wide_fast_approx::<P>());
+        (pairs.map(|pair| pair.0), pairs.map(|pair| pair.1))
+    }
+
+    #[inline(always)]
+    unsafe fn sin_restrict_fast_approx<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.sin_restrict_fast_approx::<P>())
+    }
+
+    #[inline(always)]
+    unsafe fn cos_restrict_fast_approx<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.cos_restrict_fast_approx::<P>())
+    }
+
+    #[inline(always)]
+    unsafe fn tan_fast_approx<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.tan_fast_approx::<P>())
+    }
+
+    #[inline(always)]
+    unsafe fn tan_restrict_fast_approx<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.tan_restrict_fast_approx::<P>())
+    }
+
+    #[inline(always)]
+    unsafe fn atan_fast_approx<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.atan_fast_approx::<P>())
+    }
+
+    #[inline(always)]
+    unsafe fn atan2_fast_approx<P: Precision<T>>(self, x: Self) -> Self {
+        array::from_fn(|i| self[i].atan2_fast_approx::<P>(x[i]))
+    }
+
+    #[inline(always)]
+    unsafe fn asin_fast_approx<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.asin_fast_approx::<P>())
+    }
+
+    #[inline(always)]
+    unsafe fn acos_fast_approx<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.acos_fast_approx::<P>())
+    }
+
+    #[inline(always)]
+    unsafe fn log2_fast_approx<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.log2_fast_approx::<P>())
+    }
+
+    #[inline(always)]
+    unsafe fn log10_fast_approx<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.log10_fast_approx::<P>())
+    }
+
+    #[inline(always)]
+    unsafe fn ln_fast_approx<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.ln_fast_approx::<P>())
+    }
+
+    #[inline(always)]
+    unsafe fn log_fast_approx<P: Precision<T>>(self, base: Self) -> Self {
+        array::from_fn(|i| self[i].log_fast_approx::<P>(base[i]))
+    }
+
+    #[inline(always)]
+    unsafe fn exp2_fast_approx<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.exp2_fast_approx::<P>())
+    }
+
+    #[inline(always)]
+    unsafe fn exp_fast_approx<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.exp_fast_approx::<P>())
+    }
+
+    #[inline(always)]
+    unsafe fn exp10_fast_approx<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.exp10_fast_approx::<P>())
+    }
+
+    #[inline(always)]
+    unsafe fn pow_fast_approx<P: Precision<T>>(self, exponent: Self) -> Self {
+        array::from_fn(|i| self[i].pow_fast_approx::<P>(exponent[i]))
+    }
+
+    #[inline(always)]
+    unsafe fn powi_const<const EXPONENT: i32>(self) -> Self {
+        self.map(|x| x.powi_const::<EXPONENT>())
+    }
+
+    #[inline(always)]
+    unsafe fn powf_const<P: Precision<T>, const NUMERATOR: i32, const DENOMINATOR: u32>(
+        self,
+    ) -> Self {
+        self.map(|x| x.powf_const::<P, NUMERATOR, DENOMINATOR>())
+    }
+
+    #[inline(always)]
+    unsafe fn sinh_fast_approx<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.sinh_fast_approx::<P>())
+    }
+
+    #[inline(always)]
+    unsafe fn cosh_fast_approx<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.cosh_fast_approx::<P>())
+    }
+
+    #[inline(always)]
+    unsafe fn tanh_fast_approx<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.tanh_fast_approx::<P>())
+    }
+
+    #[inline(always)]
+    unsafe fn sqrt_fast_approx<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.sqrt_fast_approx::<P>())
+    }
+
+    #[inline(always)]
+    unsafe fn rsqrt_fast_approx<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.rsqrt_fast_approx::<P>())
+    }
+
+    #[inline(always)]
+    unsafe fn cbrt_fast_approx<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.cbrt_fast_approx::<P>())
+    }
+}
+
+impl<T, const LANES: usize> FastApproxFloatChecked for [T; LANES]
+where
+    T: FastApproxFloatChecked + FastApproxFloat<Scalar = T> + Copy,
+{
+    #[inline(always)]
+    fn sin_fast_approx_checked<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.sin_fast_approx_checked::<P>())
+    }
+
+    #[inline(always)]
+    fn cos_fast_approx_checked<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.cos_fast_approx_checked::<P>())
+    }
+
+    #[inline(always)]
+    fn sincos_fast_approx_checked<P: Precision<T>>(self) -> (Self, Self) {
+        let pairs = self.map(|x| x.sincos_fast_approx_checked::<P>());
+        (pairs.map(|pair| pair.0), pairs.map(|pair| pair.1))
+    }
+
+    #[inline(always)]
+    fn sin_wide_fast_approx_checked<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.sin_wide_fast_approx_checked::<P>())
+    }
+
+    #[inline(always)]
+    fn cos_wide_fast_approx_checked<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.cos_wide_fast_approx_checked::<P>())
+    }
+
+    #[inline(always)]
+    fn sincos_wide_fast_approx_checked<P: Precision<T>>(self) -> (Self, Self) {
+        let pairs = self.map(|x| x.sincos_wide_fast_approx_checked::<P>());
+        (pairs.map(|pair| pair.0), pairs.map(|pair| pair.1))
+    }
+
+    #[inline(always)]
+    fn sin_restrict_fast_approx_checked<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.sin_restrict_fast_approx_checked::<P>())
+    }
+
+    #[inline(always)]
+    fn cos_restrict_fast_approx_checked<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.cos_restrict_fast_approx_checked::<P>())
+    }
+
+    #[inline(always)]
+    fn tan_fast_approx_checked<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.tan_fast_approx_checked::<P>())
+    }
+
+    #[inline(always)]
+    fn tan_restrict_fast_approx_checked<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.tan_restrict_fast_approx_checked::<P>())
+    }
+
+    #[inline(always)]
+    fn atan_fast_approx_checked<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.atan_fast_approx_checked::<P>())
+    }
+
+    #[inline(always)]
+    fn atan2_fast_approx_checked<P: Precision<T>>(self, x: Self) -> Self {
+        array::from_fn(|i| self[i].atan2_fast_approx_checked::<P>(x[i]))
+    }
+
+    #[inline(always)]
+    fn asin_fast_approx_checked<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.asin_fast_approx_checked::<P>())
+    }
+
+    #[inline(always)]
+    fn acos_fast_approx_checked<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.acos_fast_approx_checked::<P>())
+    }
+
+    #[inline(always)]
+    fn log2_fast_approx_checked<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.log2_fast_approx_checked::<P>())
+    }
+
+    #[inline(always)]
+    fn log10_fast_approx_checked<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.log10_fast_approx_checked::<P>())
+    }
+
+    #[inline(always)]
+    fn ln_fast_approx_checked<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.ln_fast_approx_checked::<P>())
+    }
+
+    #[inline(always)]
+    fn log_fast_approx_checked<P: Precision<T>>(self, base: Self) -> Self {
+        array::from_fn(|i| self[i].log_fast_approx_checked::<P>(base[i]))
+    }
+
+    #[inline(always)]
+    fn exp2_fast_approx_checked<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.exp2_fast_approx_checked::<P>())
+    }
+
+    #[inline(always)]
+    fn exp_fast_approx_checked<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.exp_fast_approx_checked::<P>())
+    }
+
+    #[inline(always)]
+    fn exp10_fast_approx_checked<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.exp10_fast_approx_checked::<P>())
+    }
+
+    #[inline(always)]
+    fn pow_fast_approx_checked<P: Precision<T>>(self, exponent: Self) -> Self {
+        array::from_fn(|i| self[i].pow_fast_approx_checked::<P>(exponent[i]))
+    }
+
+    #[inline(always)]
+    fn powi_const_checked<const EXPONENT: i32>(self) -> Self {
+        self.map(|x| x.powi_const_checked::<EXPONENT>())
+    }
+
+    #[inline(always)]
+    fn powf_const_checked<P: Precision<T>, const NUMERATOR: i32, const DENOMINATOR: u32>(
+        self,
+    ) -> Self {
+        self.map(|x| x.powf_const_checked::<P, NUMERATOR, DENOMINATOR>())
+    }
+
+    #[inline(always)]
+    fn sinh_fast_approx_checked<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.sinh_fast_approx_checked::<P>())
+    }
+
+    #[inline(always)]
+    fn cosh_fast_approx_checked<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.cosh_fast_approx_checked::<P>())
+    }
+
+    #[inline(always)]
+    fn tanh_fast_approx_checked<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.tanh_fast_approx_checked::<P>())
+    }
+
+    #[inline(always)]
+    fn sqrt_fast_approx_checked<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.sqrt_fast_approx_checked::<P>())
+    }
+
+    #[inline(always)]
+    fn rsqrt_fast_approx_checked<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.rsqrt_fast_approx_checked::<P>())
+    }
+
+    #[inline(always)]
+    fn cbrt_fast_approx_checked<P: Precision<T>>(self) -> Self {
+        self.map(|x| x.cbrt_fast_approx_checked::<P>())
+    }
+}
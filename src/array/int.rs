@@ -0,0 +1,153 @@
+use crate::shared::int::{FastDivisor, FastExactInt, FastExactSignedInt};
+use core::array;
+use core::ops::{Div, Rem};
+
+/// Splits the results of the scalar checked functions into the values and a mask of the lanes that
+/// didn't fail, the same as the SIMD impls. Failed lanes are set to 0.
+#[inline(always)]
+fn checked_lanes<T: Copy + Default, const LANES: usize>(
+    results: [Option<T>; LANES],
+) -> ([T; LANES], [bool; LANES]) {
+    (
+        results.map(|result| result.unwrap_or_default()),
+        results.map(|result| result.is_some()),
+    )
+}
+
+impl<T, const LANES: usize> FastExactInt for [T; LANES]
+where
+    T: FastExactInt<Checked = Option<T>> + Copy + Default,
+{
+    type Checked = (Self, [bool; LANES]);
+
+    #[inline(always)]
+    fn ilog_const_base<const BASE: u32>(self) -> Self {
+        self.map(|x| x.ilog_const_base::<BASE>())
+    }
+
+    #[inline(always)]
+    unsafe fn ilog_const_base_unchecked<const BASE: u32>(self) -> Self {
+        self.map(|x| x.ilog_const_base_unchecked::<BASE>())
+    }
+
+    #[inline(always)]
+    fn checked_ilog_const_base<const BASE: u32>(self) -> Self::Checked {
+        checked_lanes(self.map(|x| x.checked_ilog_const_base::<BASE>()))
+    }
+
+    #[inline(always)]
+    fn ilog_var_base(self, base: Self) -> Self {
+        array::from_fn(|i| self[i].ilog_var_base(base[i]))
+    }
+
+    #[inline(always)]
+    unsafe fn ilog_var_base_unchecked(self, base: Self) -> Self {
+        array::from_fn(|i| self[i].ilog_var_base_unchecked(base[i]))
+    }
+
+    #[inline(always)]
+    fn exp_const_coeff<const COEFF: u32>(self) -> Self {
+        self.map(|x| x.exp_const_coeff::<COEFF>())
+    }
+
+    #[inline(always)]
+    fn checked_exp_const_coeff<const COEFF: u32>(self) -> Self::Checked {
+        checked_lanes(self.map(|x| x.checked_exp_const_coeff::<COEFF>()))
+    }
+
+    #[inline(always)]
+    fn saturating_exp_const_coeff<const COEFF: u32>(self) -> Self {
+        self.map(|x| x.saturating_exp_const_coeff::<COEFF>())
+    }
+
+    #[inline(always)]
+    fn ipow(self, exp: Self) -> Self {
+        array::from_fn(|i| self[i].ipow(exp[i]))
+    }
+
+    #[inline(always)]
+    fn wrapping_ipow(self, exp: Self) -> Self {
+        array::from_fn(|i| self[i].wrapping_ipow(exp[i]))
+    }
+
+    #[inline(always)]
+    fn checked_ipow(self, exp: Self) -> Self::Checked {
+        checked_lanes(array::from_fn(|i| self[i].checked_ipow(exp[i])))
+    }
+
+    #[inline(always)]
+    fn saturating_ipow(self, exp: Self) -> Self {
+        array::from_fn(|i| self[i].saturating_ipow(exp[i]))
+    }
+
+    #[inline(always)]
+    fn isqrt(self) -> Self {
+        self.map(|x| x.isqrt())
+    }
+
+    #[inline(always)]
+    fn icbrt(self) -> Self {
+        self.map(|x| x.icbrt())
+    }
+
+    #[inline(always)]
+    fn iroot_const<const N: u32>(self) -> Self {
+        self.map(|x| x.iroot_const::<N>())
+    }
+
+    #[inline(always)]
+    fn div_const<const D: u32>(self) -> Self {
+        self.map(|x| x.div_const::<D>())
+    }
+
+    #[inline(always)]
+    fn rem_const<const D: u32>(self) -> Self {
+        self.map(|x| x.rem_const::<D>())
+    }
+}
+
+impl<T, const LANES: usize> FastExactSignedInt for [T; LANES]
+where
+    T: FastExactSignedInt<Checked = Option<T>> + Copy + Default,
+{
+    #[inline(always)]
+    fn exp_const_signed_coeff<const COEFF: i32>(self) -> Self {
+        self.map(|x| x.exp_const_signed_coeff::<COEFF>())
+    }
+
+    #[inline(always)]
+    fn checked_exp_const_signed_coeff<const COEFF: i32>(self) -> Self::Checked {
+        checked_lanes(self.map(|x| x.checked_exp_const_signed_coeff::<COEFF>()))
+    }
+
+    #[inline(always)]
+    fn saturating_exp_const_signed_coeff<const COEFF: i32>(self) -> Self {
+        self.map(|x| x.saturating_exp_const_signed_coeff::<COEFF>())
+    }
+}
+
+impl<T, const LANES: usize> Div<FastDivisor<T>> for [T; LANES]
+where
+    T: Div<FastDivisor<T>, Output = T> + Copy,
+    FastDivisor<T>: Copy,
+{
+    type Output = Self;
+
+    #[inline(always)]
+    fn div(self, rhs: FastDivisor<T>) -> Self {
+        self.map(|x| x / rhs)
+    }
+}
+
+impl<T, const LANES: usize> Rem<FastDivisor<T>> for [T; LANES]
+where
+    T: Rem<FastDivisor<T>, Output = T> + Copy,
+    FastDivisor<T>: Copy,
+{
+    type Output = Self;
+
+    #[inline(always)]
+    fn rem(self, rhs: FastDivisor<T>) -> Self {
+        self.map(|x| x % rhs)
+    }
+}
@@ -0,0 +1,2 @@
+mod float;
+mod int;
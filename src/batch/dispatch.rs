@@ -0,0 +1,228 @@
+// With the `runtime-dispatch` feature on x86 targets, every batch function is compiled once for
+// each of the levels below, and the level is picked with the CPU features that are detected at
+// runtime. Each function caches a pointer to the version for the detected level on its first call,
+// so later calls are just a load and an indirect call. Statics are shared between the instances of
+// a generic function, so the functions that are generic over the precision keep a pointer for each
+// level of precision, and the ones with const generic parameters, which can't be used in statics,
+// match on the cached level instead.
+//
+// The levels don't always give bit-identical results. The SIMD code only uses plain arithmetic,
+// but the scalar fast-math intrinsics that it falls back to, like the Payne-Hanek reduction of the
+// wide trig functions, can be contracted into fused multiply-adds in the AVX2 and AVX-512 versions.
+// With the `deterministic` feature, the results are the same for every level.
+
+#[cfg(all(
+    feature = "runtime-dispatch",
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+use core::sync::atomic::{AtomicPtr, AtomicU8, Ordering};
+
+#[cfg(all(
+    feature = "runtime-dispatch",
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub(crate) enum Level {
+    /// The target features that the crate was compiled with.
+    Baseline = 1,
+    Avx2 = 2,
+    Avx512 = 3,
+}
+
+#[cfg(all(
+    feature = "runtime-dispatch",
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+static LEVEL: AtomicU8 = AtomicU8::new(0);
+
+#[cfg(all(
+    feature = "runtime-dispatch",
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+impl Level {
+    #[cfg(test)]
+    pub(crate) const ALL: [Level; 3] = [Level::Baseline, Level::Avx2, Level::Avx512];
+
+    /// Whether the CPU has the target features that the level is compiled with.
+    pub(crate) fn is_supported(self) -> bool {
+        match self {
+            Level::Baseline => true,
+            Level::Avx2 => {
+                std::is_x86_feature_detected!("avx2") && std::is_x86_feature_detected!("fma")
+            }
+            Level::Avx512 => std::is_x86_feature_detected!("avx512f"),
+        }
+    }
+}
+
+#[cfg(all(
+    feature = "runtime-dispatch",
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+#[cold]
+fn detect() -> Level {
+    let level = if Level::Avx512.is_supported() {
+        Level::Avx512
+    } else if Level::Avx2.is_supported() {
+        Level::Avx2
+    } else {
+        Level::Baseline
+    };
+
+    LEVEL.store(level as u8, Ordering::Relaxed);
+    level
+}
+
+/// Replaces the cached level, so that tests can run the batch functions at every level that the
+/// CPU supports instead of only the best one. The function pointers aren't cached in tests, so
+/// that they follow the level.
+#[cfg(all(
+    test,
+    feature = "runtime-dispatch",
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+pub(crate) fn set_level(level: Level) {
+    assert!(level.is_supported(), "the CPU doesn't support this level");
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+#[cfg(all(
+    feature = "runtime-dispatch",
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+#[inline(always)]
+pub(crate) fn level() -> Level {
+    match LEVEL.load(Ordering::Relaxed) {
+        1 => Level::Baseline,
+        2 => Level::Avx2,
+        3 => Level::Avx512,
+        _ => detect(),
+    }
+}
+
+/// Returns the function that `cache` points to, or resolves it and stores it on the first call.
+#[cfg(all(
+    feature = "runtime-dispatch",
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+#[inline(always)]
+pub(crate) fn cached(cache: &AtomicPtr<()>, resolve: impl FnOnce() -> *mut ()) -> *mut () {
+    let function = cache.load(Ordering::Relaxed);
+    if cfg!(not(test)) && !function.is_null() {
+        return function;
+    }
+
+    let function = resolve();
+    cache.store(function, Ordering::Relaxed);
+    function
+}
+
+#[cfg(all(
+    feature = "runtime-dispatch",
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+/// Compiles the body once for each level, with `Vector` set to the widest vector of the level.
+macro_rules! vector_dispatch {
+    ([], [], ($($param:ident: $ty:ty),*), $body:expr) => {{
+        static FUNCTION: core::sync::atomic::AtomicPtr<()> =
+            core::sync::atomic::AtomicPtr::new(core::ptr::null_mut());
+
+        crate::batch::dispatch::vector_dispatch!(
+            @cached FUNCTION,
+            [],
+            [],
+            ($($param: $ty),*),
+            $body
+        )
+    }};
+    ([P: Precision<Scalar>], [P], ($($param:ident: $ty:ty),*), $body:expr) => {{
+        static FUNCTIONS: [core::sync::atomic::AtomicPtr<()>; 8] =
+            [const { core::sync::atomic::AtomicPtr::new(core::ptr::null_mut()) }; 8];
+
+        crate::batch::dispatch::vector_dispatch!(
+            @cached FUNCTIONS[P::LEVEL],
+            [P: Precision<Scalar>],
+            [P],
+            ($($param: $ty),*),
+            $body
+        )
+    }};
+    ([$($gen:tt)*], [$($arg:tt)*], ($($param:ident: $ty:ty),*), $body:expr) => {{
+        crate::batch::dispatch::vector_dispatch!(@levels [$($gen)*], ($($param: $ty),*), $body);
+
+        // SAFETY: the features for each level were checked by `level`
+        unsafe {
+            match crate::batch::dispatch::level() {
+                crate::batch::dispatch::Level::Avx512 => avx512::<$($arg)*>($($param),*),
+                crate::batch::dispatch::Level::Avx2 => avx2::<$($arg)*>($($param),*),
+                crate::batch::dispatch::Level::Baseline => baseline::<$($arg)*>($($param),*),
+            }
+        }
+    }};
+    (
+        @cached $cache:expr,
+        [$($gen:tt)*],
+        [$($arg:tt)*],
+        ($($param:ident: $ty:ty),*),
+        $body:expr
+    ) => {{
+        crate::batch::dispatch::vector_dispatch!(@levels [$($gen)*], ($($param: $ty),*), $body);
+
+        let function = crate::batch::dispatch::cached(&$cache, || {
+            let function: unsafe fn($($ty),*) = match crate::batch::dispatch::level() {
+                crate::batch::dispatch::Level::Avx512 => avx512::<$($arg)*>,
+                crate::batch::dispatch::Level::Avx2 => avx2::<$($arg)*>,
+                crate::batch::dispatch::Level::Baseline => baseline::<$($arg)*>,
+            };
+            function as *mut ()
+        });
+
+        // SAFETY: the pointer was cast from a function of the same type, for a level that was
+        // checked by `level`
+        unsafe { core::mem::transmute::<*mut (), unsafe fn($($ty),*)>(function)($($param),*) }
+    }};
+    (@levels [$($gen:tt)*], ($($param:ident: $ty:ty),*), $body:expr) => {
+        #[target_feature(enable = "avx512f")]
+        unsafe fn avx512<$($gen)*>($($param: $ty),*) {
+            type Vector = Simd<Scalar, { crate::batch::lane_count::<Scalar>(64) }>;
+            $body
+        }
+
+        #[target_feature(enable = "avx2,fma")]
+        unsafe fn avx2<$($gen)*>($($param: $ty),*) {
+            type Vector = Simd<Scalar, { crate::batch::lane_count::<Scalar>(32) }>;
+            $body
+        }
+
+        #[inline(always)]
+        unsafe fn baseline<$($gen)*>($($param: $ty),*) {
+            type Vector =
+                Simd<Scalar, { crate::batch::lane_count::<Scalar>(crate::batch::VECTOR_BYTES) }>;
+            $body
+        }
+    };
+}
+
+/// Compiles the body with `Vector` set to the widest vector that the crate was compiled for.
+#[cfg(not(all(
+    feature = "runtime-dispatch",
+    any(target_arch = "x86", target_arch = "x86_64")
+)))]
+macro_rules! vector_dispatch {
+    ([$($gen:tt)*], [$($arg:tt)*], ($($param:ident: $ty:ty),*), $body:expr) => {{
+        type Vector =
+            Simd<Scalar, { crate::batch::lane_count::<Scalar>(crate::batch::VECTOR_BYTES) }>;
+        $body
+    }};
+}
+
+/// Compiles the body with `Vector` set to the scalar type, for the types without SIMD lanes.
+macro_rules! scalar_dispatch {
+    ([$($gen:tt)*], [$($arg:tt)*], ($($param:ident: $ty:ty),*), $body:expr) => {{
+        type Vector = Scalar;
+        $body
+    }};
+}
+
+pub(crate) use {scalar_dispatch, vector_dispatch};
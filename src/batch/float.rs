@@ -0,0 +1,215 @@
+macro_rules! pair_batch {
+    (unsafe $trait:ident, $name:ident) => {
+        #[doc = concat!(
+                    "Calls [`", stringify!($trait), "::", stringify!($name),
+                    "`] on each element of `input`, and writes the sines to `sin_output` and the ",
+                    "cosines to `cos_output`."
+                )]
+        ///
+        /// # Safety
+        /// Every element of `input` must be a valid input for the function.
+        ///
+        /// # Panics
+        /// Panics if the slices have different lengths.
+        #[inline]
+        pub unsafe fn $name<P: Precision<Scalar>>(
+            input: &[Scalar],
+            sin_output: &mut [Scalar],
+            cos_output: &mut [Scalar],
+        ) {
+            dispatch!(
+                [P: Precision<Scalar>],
+                [P],
+                (input: &[Scalar], sin_output: &mut [Scalar], cos_output: &mut [Scalar]),
+                map_pair(input, sin_output, cos_output, |x: Vector| x.$name::<P>())
+            );
+        }
+    };
+    ($trait:ident, $name:ident) => {
+        #[doc = concat!(
+                    "Calls [`", stringify!($trait), "::", stringify!($name),
+                    "`] on each element of `input`, and writes the sines to `sin_output` and the ",
+                    "cosines to `cos_output`."
+                )]
+        ///
+        /// # Panics
+        /// Panics if the slices have different lengths.
+        #[inline]
+        pub fn $name<P: Precision<Scalar>>(
+            input: &[Scalar],
+            sin_output: &mut [Scalar],
+            cos_output: &mut [Scalar],
+        ) {
+            dispatch!(
+                [P: Precision<Scalar>],
+                [P],
+                (input: &[Scalar], sin_output: &mut [Scalar], cos_output: &mut [Scalar]),
+                map_pair(input, sin_output, cos_output, |x: Vector| x.$name::<P>())
+            );
+        }
+    };
+}
+
+macro_rules! float_batch {
+    ($f:ident) => {
+        use crate::batch::float::pair_batch;
+        use crate::batch::macros::{binary_batch, unary_batch};
+        use crate::batch::vector::{map, map2, map2_in_place, map_in_place, map_pair};
+        use crate::shared::float::{FastApproxFloat, FastApproxFloatChecked, Precision};
+        use crate::batch::dispatch::vector_dispatch as dispatch;
+        use core::simd::Simd;
+
+        type Scalar = $f;
+
+        crate::batch::float::float_batch!(
+            @approx unsafe FastApproxFloat,
+            sin_fast_approx, sin_fast_approx_in_place,
+            cos_fast_approx, cos_fast_approx_in_place,
+            sin_wide_fast_approx, sin_wide_fast_approx_in_place,
+            cos_wide_fast_approx, cos_wide_fast_approx_in_place,
+            sin_restrict_fast_approx, sin_restrict_fast_approx_in_place,
+            cos_restrict_fast_approx, cos_restrict_fast_approx_in_place,
+            tan_fast_approx, tan_fast_approx_in_place,
+            tan_restrict_fast_approx, tan_restrict_fast_approx_in_place,
+            atan_fast_approx, atan_fast_approx_in_place,
+            asin_fast_approx, asin_fast_approx_in_place,
+            acos_fast_approx, acos_fast_approx_in_place,
+            log2_fast_approx, log2_fast_approx_in_place,
+            log10_fast_approx, log10_fast_approx_in_place,
+            ln_fast_approx, ln_fast_approx_in_place,
+            exp2_fast_approx, exp2_fast_approx_in_place,
+            exp_fast_approx, exp_fast_approx_in_place,
+            exp10_fast_approx, exp10_fast_approx_in_place,
+            sinh_fast_approx, sinh_fast_approx_in_place,
+            cosh_fast_approx, cosh_fast_approx_in_place,
+            tanh_fast_approx, tanh_fast_approx_in_place,
+            sqrt_fast_approx, sqrt_fast_approx_in_place,
+            rsqrt_fast_approx, rsqrt_fast_approx_in_place,
+            cbrt_fast_approx, cbrt_fast_approx_in_place
+        );
+        crate::batch::float::float_batch!(
+            @approx FastApproxFloatChecked,
+            sin_fast_approx_checked, sin_fast_approx_checked_in_place,
+            cos_fast_approx_checked, cos_fast_approx_checked_in_place,
+            sin_wide_fast_approx_checked, sin_wide_fast_approx_checked_in_place,
+            cos_wide_fast_approx_checked, cos_wide_fast_approx_checked_in_place,
+            sin_restrict_fast_approx_checked, sin_restrict_fast_approx_checked_in_place,
+            cos_restrict_fast_approx_checked, cos_restrict_fast_approx_checked_in_place,
+            tan_fast_approx_checked, tan_fast_approx_checked_in_place,
+            tan_restrict_fast_approx_checked, tan_restrict_fast_approx_checked_in_place,
+            atan_fast_approx_checked, atan_fast_approx_checked_in_place,
+            asin_fast_approx_checked, asin_fast_approx_checked_in_place,
+            acos_fast_approx_checked, acos_fast_approx_checked_in_place,
+            log2_fast_approx_checked, log2_fast_approx_checked_in_place,
+            log10_fast_approx_checked, log10_fast_approx_checked_in_place,
+            ln_fast_approx_checked, ln_fast_approx_checked_in_place,
+            exp2_fast_approx_checked, exp2_fast_approx_checked_in_place,
+            exp_fast_approx_checked, exp_fast_approx_checked_in_place,
+            exp10_fast_approx_checked, exp10_fast_approx_checked_in_place,
+            sinh_fast_approx_checked, sinh_fast_approx_checked_in_place,
+            cosh_fast_approx_checked, cosh_fast_approx_checked_in_place,
+            tanh_fast_approx_checked, tanh_fast_approx_checked_in_place,
+            sqrt_fast_approx_checked, sqrt_fast_approx_checked_in_place,
+            rsqrt_fast_approx_checked, rsqrt_fast_approx_checked_in_place,
+            cbrt_fast_approx_checked, cbrt_fast_approx_checked_in_place
+        );
+
+        unary_batch!(
+            unsafe FastApproxFloat,
+            powi_const,
+            powi_const_in_place,
+            [const EXPONENT: i32],
+            [EXPONENT]
+        );
+        unary_batch!(
+            FastApproxFloatChecked,
+            powi_const_checked,
+            powi_const_checked_in_place,
+            [const EXPONENT: i32],
+            [EXPONENT]
+        );
+        unary_batch!(
+            unsafe FastApproxFloat,
+            powf_const,
+            powf_const_in_place,
+            [P: Precision<Scalar>, const NUMERATOR: i32, const DENOMINATOR: u32],
+            [P, NUMERATOR, DENOMINATOR]
+        );
+        unary_batch!(
+            FastApproxFloatChecked,
+            powf_const_checked,
+            powf_const_checked_in_place,
+            [P: Precision<Scalar>, const NUMERATOR: i32, const DENOMINATOR: u32],
+            [P, NUMERATOR, DENOMINATOR]
+        );
+
+        binary_batch!(
+            unsafe FastApproxFloat,
+            atan2_fast_approx,
+            atan2_fast_approx_in_place,
+            y,
+            x,
+            [P: Precision<Scalar>],
+            [P]
+        );
+        binary_batch!(
+            FastApproxFloatChecked,
+            atan2_fast_approx_checked,
+            atan2_fast_approx_checked_in_place,
+            y,
+            x,
+            [P: Precision<Scalar>],
+            [P]
+        );
+        binary_batch!(
+            unsafe FastApproxFloat,
+            log_fast_approx,
+            log_fast_approx_in_place,
+            x,
+            base,
+            [P: Precision<Scalar>],
+            [P]
+        );
+        binary_batch!(
+            FastApproxFloatChecked,
+            log_fast_approx_checked,
+            log_fast_approx_checked_in_place,
+            x,
+            base,
+            [P: Precision<Scalar>],
+            [P]
+        );
+        binary_batch!(
+            unsafe FastApproxFloat,
+            pow_fast_approx,
+            pow_fast_approx_in_place,
+            x,
+            exponent,
+            [P: Precision<Scalar>],
+            [P]
+        );
+        binary_batch!(
+            FastApproxFloatChecked,
+            pow_fast_approx_checked,
+            pow_fast_approx_checked_in_place,
+            x,
+            exponent,
+            [P: Precision<Scalar>],
+            [P]
+        );
+
+        pair_batch!(unsafe FastApproxFloat, sincos_fast_approx);
+        pair_batch!(FastApproxFloatChecked, sincos_fast_approx_checked);
+        pair_batch!(unsafe FastApproxFloat, sincos_wide_fast_approx);
+        pair_batch!(FastApproxFloatChecked, sincos_wide_fast_approx_checked);
+    };
+    // the functions that only take a precision level
+    (@approx unsafe $trait:ident, $($name:ident, $in_place:ident),*) => {
+        $(unary_batch!(unsafe $trait, $name, $in_place, [P: Precision<Scalar>], [P]);)*
+    };
+    (@approx $trait:ident, $($name:ident, $in_place:ident),*) => {
+        $(unary_batch!($trait, $name, $in_place, [P: Precision<Scalar>], [P]);)*
+    };
+}
+
+pub(crate) use {float_batch, pair_batch};
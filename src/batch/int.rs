@@ -0,0 +1,107 @@
+macro_rules! int_batch {
+    (vector, $t:ident) => {
+        use crate::batch::vector::{
+            map, map2, map2_checked, map2_in_place, map_checked, map_in_place,
+        };
+        use crate::batch::dispatch::vector_dispatch as dispatch;
+        use core::simd::Simd;
+
+        crate::batch::int::int_batch!($t);
+    };
+    (scalar, $t:ident) => {
+        use crate::batch::scalar::{
+            map, map2, map2_checked, map2_in_place, map_checked, map_in_place,
+        };
+        use crate::batch::dispatch::scalar_dispatch as dispatch;
+
+        crate::batch::int::int_batch!($t);
+    };
+    ($t:ident) => {
+        use crate::batch::macros::{binary_batch, checked_batch, unary_batch};
+        use crate::shared::int::FastExactInt;
+
+        type Scalar = $t;
+
+        unary_batch!(
+            FastExactInt,
+            ilog_const_base,
+            ilog_const_base_in_place,
+            [const BASE: u32],
+            [BASE]
+        );
+        unary_batch!(
+            unsafe FastExactInt,
+            ilog_const_base_unchecked,
+            ilog_const_base_unchecked_in_place,
+            [const BASE: u32],
+            [BASE]
+        );
+        checked_batch!(FastExactInt, checked_ilog_const_base, [const BASE: u32], [BASE]);
+        binary_batch!(
+            FastExactInt,
+            ilog_var_base,
+            ilog_var_base_in_place,
+            x,
+            base,
+            [],
+            []
+        );
+        binary_batch!(
+            unsafe FastExactInt,
+            ilog_var_base_unchecked,
+            ilog_var_base_unchecked_in_place,
+            x,
+            base,
+            [],
+            []
+        );
+        unary_batch!(
+            FastExactInt,
+            exp_const_coeff,
+            exp_const_coeff_in_place,
+            [const COEFF: u32],
+            [COEFF]
+        );
+        checked_batch!(FastExactInt, checked_exp_const_coeff, [const COEFF: u32], [COEFF]);
+        unary_batch!(
+            FastExactInt,
+            saturating_exp_const_coeff,
+            saturating_exp_const_coeff_in_place,
+            [const COEFF: u32],
+            [COEFF]
+        );
+        binary_batch!(FastExactInt, ipow, ipow_in_place, x, exp, [], []);
+        binary_batch!(
+            FastExactInt,
+            wrapping_ipow,
+            wrapping_ipow_in_place,
+            x,
+            exp,
+            [],
+            []
+        );
+        checked_batch!(FastExactInt, checked_ipow, x, exp);
+        binary_batch!(
+            FastExactInt,
+            saturating_ipow,
+            saturating_ipow_in_place,
+            x,
+            exp,
+            [],
+            []
+        );
+        unary_batch!(FastExactInt, isqrt, isqrt_in_place, [], []);
+        unary_batch!(FastExactInt, icbrt, icbrt_in_place, [], []);
+        unary_batch!(
+            FastExactInt,
+            iroot_const,
+            iroot_const_in_place,
+            [const N: u32],
+            [N]
+        );
+        unary_batch!(FastExactInt, div_const, div_const_in_place, [const D: u32], [D]);
+        unary_batch!(FastExactInt, rem_const, rem_const_in_place, [const D: u32], [D]);
+    };
+}
+
+pub(crate) use int_batch;
@@ -0,0 +1,227 @@
+// These expect the `Scalar` type, the `dispatch` macro, and the helper functions from either
+// `vector` or `scalar` to be in scope. The `dispatch` macro defines the `Vector` type for the
+// body that it's given. Each function is defined with its name, the name of its in-place variant,
+// the generic parameters of the function, and the arguments that the generic parameters are
+// passed to the function with.
+
+macro_rules! unary_batch {
+    (unsafe $trait:ident, $name:ident, $in_place:ident, [$($gen:tt)*], [$($arg:tt)*]) => {
+        #[doc = concat!(
+            "Calls [`", stringify!($trait), "::", stringify!($name),
+            "`] on each element of `input`, and writes the results to `output`."
+        )]
+        ///
+        /// # Safety
+        /// Every element of `input` must be a valid input for the function.
+        ///
+        /// # Panics
+        /// Panics if `input` and `output` have different lengths.
+        #[inline]
+        pub unsafe fn $name<$($gen)*>(input: &[Scalar], output: &mut [Scalar]) {
+            dispatch!(
+                [$($gen)*],
+                [$($arg)*],
+                (input: &[Scalar], output: &mut [Scalar]),
+                map(input, output, |x: Vector| x.$name::<$($arg)*>())
+            );
+        }
+
+        #[doc = concat!(
+            "Calls [`", stringify!($trait), "::", stringify!($name),
+            "`] on each element of `data`, and writes the results back to `data`."
+        )]
+        ///
+        /// # Safety
+        /// Every element of `data` must be a valid input for the function.
+        #[inline]
+        pub unsafe fn $in_place<$($gen)*>(data: &mut [Scalar]) {
+            dispatch!(
+                [$($gen)*],
+                [$($arg)*],
+                (data: &mut [Scalar]),
+                map_in_place(data, |x: Vector| x.$name::<$($arg)*>())
+            );
+        }
+    };
+    ($trait:ident, $name:ident, $in_place:ident, [$($gen:tt)*], [$($arg:tt)*]) => {
+        #[doc = concat!(
+            "Calls [`", stringify!($trait), "::", stringify!($name),
+            "`] on each element of `input`, and writes the results to `output`."
+        )]
+        ///
+        /// # Panics
+        /// Panics if `input` and `output` have different lengths.
+        #[inline]
+        pub fn $name<$($gen)*>(input: &[Scalar], output: &mut [Scalar]) {
+            dispatch!(
+                [$($gen)*],
+                [$($arg)*],
+                (input: &[Scalar], output: &mut [Scalar]),
+                map(input, output, |x: Vector| x.$name::<$($arg)*>())
+            );
+        }
+
+        #[doc = concat!(
+            "Calls [`", stringify!($trait), "::", stringify!($name),
+            "`] on each element of `data`, and writes the results back to `data`."
+        )]
+        #[inline]
+        pub fn $in_place<$($gen)*>(data: &mut [Scalar]) {
+            dispatch!(
+                [$($gen)*],
+                [$($arg)*],
+                (data: &mut [Scalar]),
+                map_in_place(data, |x: Vector| x.$name::<$($arg)*>())
+            );
+        }
+    };
+}
+
+macro_rules! binary_batch {
+    (
+        unsafe $trait:ident,
+        $name:ident,
+        $in_place:ident,
+        $first:ident,
+        $second:ident,
+        [$($gen:tt)*],
+        [$($arg:tt)*]
+    ) => {
+        #[doc = concat!(
+            "Calls [`", stringify!($trait), "::", stringify!($name),
+            "`] on each pair of elements of `", stringify!($first), "` and `",
+            stringify!($second), "`, and writes the results to `output`."
+        )]
+        ///
+        /// # Safety
+        /// Every pair of elements must be a valid input for the function.
+        ///
+        /// # Panics
+        /// Panics if the slices have different lengths.
+        #[inline]
+        pub unsafe fn $name<$($gen)*>(
+            $first: &[Scalar],
+            $second: &[Scalar],
+            output: &mut [Scalar],
+        ) {
+            dispatch!(
+                [$($gen)*],
+                [$($arg)*],
+                ($first: &[Scalar], $second: &[Scalar], output: &mut [Scalar]),
+                map2($first, $second, output, |a: Vector, b: Vector| a.$name::<$($arg)*>(b))
+            );
+        }
+
+        #[doc = concat!(
+            "Calls [`", stringify!($trait), "::", stringify!($name),
+            "`] on each pair of elements of `", stringify!($first), "` and `",
+            stringify!($second), "`, and writes the results back to `", stringify!($first),
+            "`."
+        )]
+        ///
+        /// # Safety
+        /// Every pair of elements must be a valid input for the function.
+        ///
+        /// # Panics
+        /// Panics if the slices have different lengths.
+        #[inline]
+        pub unsafe fn $in_place<$($gen)*>($first: &mut [Scalar], $second: &[Scalar]) {
+            dispatch!(
+                [$($gen)*],
+                [$($arg)*],
+                ($first: &mut [Scalar], $second: &[Scalar]),
+                map2_in_place($first, $second, |a: Vector, b: Vector| a.$name::<$($arg)*>(b))
+            );
+        }
+    };
+    (
+        $trait:ident,
+        $name:ident,
+        $in_place:ident,
+        $first:ident,
+        $second:ident,
+        [$($gen:tt)*],
+        [$($arg:tt)*]
+    ) => {
+        #[doc = concat!(
+            "Calls [`", stringify!($trait), "::", stringify!($name),
+            "`] on each pair of elements of `", stringify!($first), "` and `",
+            stringify!($second), "`, and writes the results to `output`."
+        )]
+        ///
+        /// # Panics
+        /// Panics if the slices have different lengths.
+        #[inline]
+        pub fn $name<$($gen)*>($first: &[Scalar], $second: &[Scalar], output: &mut [Scalar]) {
+            dispatch!(
+                [$($gen)*],
+                [$($arg)*],
+                ($first: &[Scalar], $second: &[Scalar], output: &mut [Scalar]),
+                map2($first, $second, output, |a: Vector, b: Vector| a.$name::<$($arg)*>(b))
+            );
+        }
+
+        #[doc = concat!(
+            "Calls [`", stringify!($trait), "::", stringify!($name),
+            "`] on each pair of elements of `", stringify!($first), "` and `",
+            stringify!($second), "`, and writes the results back to `", stringify!($first),
+            "`."
+        )]
+        ///
+        /// # Panics
+        /// Panics if the slices have different lengths.
+        #[inline]
+        pub fn $in_place<$($gen)*>($first: &mut [Scalar], $second: &[Scalar]) {
+            dispatch!(
+                [$($gen)*],
+                [$($arg)*],
+                ($first: &mut [Scalar], $second: &[Scalar]),
+                map2_in_place($first, $second, |a: Vector, b: Vector| a.$name::<$($arg)*>(b))
+            );
+        }
+    };
+}
+
+/// The checked integer functions, which write `None` for each element without a result. These
+/// don't have in-place variants, because the output type is different from the input type.
+macro_rules! checked_batch {
+    ($trait:ident, $name:ident, [$($gen:tt)*], [$($arg:tt)*]) => {
+        #[doc = concat!(
+            "Calls [`", stringify!($trait), "::", stringify!($name),
+            "`] on each element of `input`, and writes the results to `output`."
+        )]
+        ///
+        /// # Panics
+        /// Panics if `input` and `output` have different lengths.
+        #[inline]
+        pub fn $name<$($gen)*>(input: &[Scalar], output: &mut [Option<Scalar>]) {
+            dispatch!(
+                [$($gen)*],
+                [$($arg)*],
+                (input: &[Scalar], output: &mut [Option<Scalar>]),
+                map_checked(input, output, |x: Vector| x.$name::<$($arg)*>())
+            );
+        }
+    };
+    ($trait:ident, $name:ident, $first:ident, $second:ident) => {
+        #[doc = concat!(
+            "Calls [`", stringify!($trait), "::", stringify!($name),
+            "`] on each pair of elements of `", stringify!($first), "` and `",
+            stringify!($second), "`, and writes the results to `output`."
+        )]
+        ///
+        /// # Panics
+        /// Panics if the slices have different lengths.
+        #[inline]
+        pub fn $name($first: &[Scalar], $second: &[Scalar], output: &mut [Option<Scalar>]) {
+            dispatch!(
+                [],
+                [],
+                ($first: &[Scalar], $second: &[Scalar], output: &mut [Option<Scalar>]),
+                map2_checked($first, $second, output, |a: Vector, b: Vector| a.$name(b))
+            );
+        }
+    };
+}
+
+pub(crate) use {binary_batch, checked_batch, unary_batch};
@@ -0,0 +1,98 @@
+use core::mem::size_of;
+
+pub(crate) mod dispatch;
+mod float;
+mod int;
+mod macros;
+mod scalar;
+mod vector;
+
+use float::float_batch;
+use int::int_batch;
+
+// the widest vector registers that the target is compiled for. wider vectors would only be split
+// up by the compiler, and narrower ones would leave part of each register unused.
+const VECTOR_BYTES: usize = if cfg!(target_feature = "avx512f") {
+    64
+} else if cfg!(target_feature = "avx") {
+    32
+} else {
+    16
+};
+
+/// The amount of lanes of `T` that fill a vector register with the given size.
+const fn lane_count<T>(vector_bytes: usize) -> usize {
+    vector_bytes / size_of::<T>()
+}
+
+/// Batch functions for `f32` slices.
+pub mod f32 {
+    super::float_batch!(f32);
+}
+
+/// Batch functions for `f64` slices.
+pub mod f64 {
+    super::float_batch!(f64);
+}
+
+/// Batch functions for `u8` slices.
+pub mod u8 {
+    super::int_batch!(vector, u8);
+}
+
+/// Batch functions for `u16` slices.
+pub mod u16 {
+    super::int_batch!(vector, u16);
+}
+
+/// Batch functions for `u32` slices.
+pub mod u32 {
+    super::int_batch!(vector, u32);
+}
+
+/// Batch functions for `u64` slices.
+pub mod u64 {
+    super::int_batch!(vector, u64);
+}
+
+/// Batch functions for `u128` slices. There aren't any 128-bit SIMD lanes, so these use the scalar
+/// functions.
+pub mod u128 {
+    super::int_batch!(scalar, u128);
+}
+
+/// Batch functions for `usize` slices.
+pub mod usize {
+    super::int_batch!(vector, usize);
+}
+
+/// Batch functions for `i8` slices.
+pub mod i8 {
+    super::int_batch!(vector, i8);
+}
+
+/// Batch functions for `i16` slices.
+pub mod i16 {
+    super::int_batch!(vector, i16);
+}
+
+/// Batch functions for `i32` slices.
+pub mod i32 {
+    super::int_batch!(vector, i32);
+}
+
+/// Batch functions for `i64` slices.
+pub mod i64 {
+    super::int_batch!(vector, i64);
+}
+
+/// Batch functions for `i128` slices. There aren't any 128-bit SIMD lanes, so these use the scalar
+/// functions.
+pub mod i128 {
+    super::int_batch!(scalar, i128);
+}
+
+/// Batch functions for `isize` slices.
+pub mod isize {
+    super::int_batch!(vector, isize);
+}
@@ -0,0 +1,65 @@
+// These have the same signatures as the functions in `vector`, but call the scalar functions on
+// each element, for the types that don't have SIMD lanes.
+
+#[inline(always)]
+pub(super) fn map<T: Copy>(input: &[T], output: &mut [T], f: impl Fn(T) -> T) {
+    assert_eq!(input.len(), output.len(), "slice lengths differ");
+    for (&x, result) in input.iter().zip(output) {
+        *result = f(x);
+    }
+}
+
+#[inline(always)]
+pub(super) fn map_in_place<T: Copy>(data: &mut [T], f: impl Fn(T) -> T) {
+    for element in data {
+        *element = f(*element);
+    }
+}
+
+#[inline(always)]
+pub(super) fn map_checked<T: Copy>(
+    input: &[T],
+    output: &mut [Option<T>],
+    f: impl Fn(T) -> Option<T>,
+) {
+    assert_eq!(input.len(), output.len(), "slice lengths differ");
+    for (&x, result) in input.iter().zip(output) {
+        *result = f(x);
+    }
+}
+
+#[inline(always)]
+pub(super) fn map2<T: Copy>(
+    first_input: &[T],
+    second_input: &[T],
+    output: &mut [T],
+    f: impl Fn(T, T) -> T,
+) {
+    assert_eq!(first_input.len(), output.len(), "slice lengths differ");
+    assert_eq!(second_input.len(), output.len(), "slice lengths differ");
+    for ((&x, &y), result) in first_input.iter().zip(second_input).zip(output) {
+        *result = f(x, y);
+    }
+}
+
+#[inline(always)]
+pub(super) fn map2_in_place<T: Copy>(data: &mut [T], second_input: &[T], f: impl Fn(T, T) -> T) {
+    assert_eq!(second_input.len(), data.len(), "slice lengths differ");
+    for (element, &y) in data.iter_mut().zip(second_input) {
+        *element = f(*element, y);
+    }
+}
+
+#[inline(always)]
+pub(super) fn map2_checked<T: Copy>(
+    first_input: &[T],
+    second_input: &[T],
+    output: &mut [Option<T>],
+    f: impl Fn(T, T) -> Option<T>,
+) {
+    assert_eq!(first_input.len(), output.len(), "slice lengths differ");
+    assert_eq!(second_input.len(), output.len(), "slice lengths differ");
+    for ((&x, &y), result) in first_input.iter().zip(second_input).zip(output) {
+        *result = f(x, y);
+    }
+}
@@ -0,0 +1,197 @@
+// Every vector is calculated with the SIMD functions, including the partial ones at the ends of
+// the slices. This keeps the results the same regardless of where the slice starts, because the
+// scalar and SIMD functions can differ by a few ULPs.
+
+use core::iter::once;
+use core::ops::Range;
+use core::simd::*;
+
+/// Returns the range of each vector in a slice. The unaligned head and the remainder of the slice
+/// are split off into partial vectors, so every full vector is aligned.
+#[inline(always)]
+fn vector_ranges<T, const LANES: usize>(slice: &[T]) -> impl Iterator<Item = Range<usize>>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let len = slice.len();
+    let head_len = slice.as_simd::<LANES>().0.len();
+    let body_end = head_len + (len - head_len) / LANES * LANES;
+
+    once(0..head_len)
+        .chain(
+            (head_len..body_end)
+                .step_by(LANES)
+                .map(|start| start..start + LANES),
+        )
+        .chain(once(body_end..len))
+        .filter(|range| !range.is_empty())
+}
+
+/// Loads a vector from a slice of up to `LANES` elements. Missing lanes are filled with the first
+/// element, so they're always valid inputs for the function that the vector is passed to.
+#[inline(always)]
+fn load<T, const LANES: usize>(slice: &[T]) -> Simd<T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    if slice.len() == LANES {
+        Simd::from_slice(slice)
+    } else {
+        let mut vector = Simd::splat(slice[0]);
+        vector.as_mut_array()[..slice.len()].copy_from_slice(slice);
+        vector
+    }
+}
+
+/// Stores as many lanes of a vector as the slice has room for.
+#[inline(always)]
+fn store<T, const LANES: usize>(vector: Simd<T, LANES>, slice: &mut [T])
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    slice.copy_from_slice(&vector.as_array()[..slice.len()]);
+}
+
+/// Stores as many lanes of a vector as the slice has room for, with the lanes that aren't set in
+/// the mask stored as `None`.
+#[inline(always)]
+fn store_checked<T, M, const LANES: usize>(
+    (vector, valid): (Simd<T, LANES>, Mask<M, LANES>),
+    slice: &mut [Option<T>],
+) where
+    T: SimdElement,
+    M: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    for (i, element) in slice.iter_mut().enumerate() {
+        *element = valid.test(i).then_some(vector[i]);
+    }
+}
+
+#[inline(always)]
+pub(super) fn map<T, const LANES: usize>(
+    input: &[T],
+    output: &mut [T],
+    f: impl Fn(Simd<T, LANES>) -> Simd<T, LANES>,
+) where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    assert_eq!(input.len(), output.len(), "slice lengths differ");
+    for range in vector_ranges::<T, LANES>(input) {
+        store(f(load(&input[range.clone()])), &mut output[range]);
+    }
+}
+
+#[inline(always)]
+pub(super) fn map_in_place<T, const LANES: usize>(
+    data: &mut [T],
+    f: impl Fn(Simd<T, LANES>) -> Simd<T, LANES>,
+) where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    for range in vector_ranges::<T, LANES>(data) {
+        store(f(load(&data[range.clone()])), &mut data[range]);
+    }
+}
+
+#[inline(always)]
+pub(super) fn map_checked<T, M, const LANES: usize>(
+    input: &[T],
+    output: &mut [Option<T>],
+    f: impl Fn(Simd<T, LANES>) -> (Simd<T, LANES>, Mask<M, LANES>),
+) where
+    T: SimdElement,
+    M: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    assert_eq!(input.len(), output.len(), "slice lengths differ");
+    for range in vector_ranges::<T, LANES>(input) {
+        store_checked(f(load(&input[range.clone()])), &mut output[range]);
+    }
+}
+
+#[inline(always)]
+pub(super) fn map_pair<T, const LANES: usize>(
+    input: &[T],
+    first_output: &mut [T],
+    second_output: &mut [T],
+    f: impl Fn(Simd<T, LANES>) -> (Simd<T, LANES>, Simd<T, LANES>),
+) where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    assert_eq!(input.len(), first_output.len(), "slice lengths differ");
+    assert_eq!(input.len(), second_output.len(), "slice lengths differ");
+    for range in vector_ranges::<T, LANES>(input) {
+        let (first, second) = f(load(&input[range.clone()]));
+        store(first, &mut first_output[range.clone()]);
+        store(second, &mut second_output[range]);
+    }
+}
+
+#[inline(always)]
+pub(super) fn map2<T, const LANES: usize>(
+    first_input: &[T],
+    second_input: &[T],
+    output: &mut [T],
+    f: impl Fn(Simd<T, LANES>, Simd<T, LANES>) -> Simd<T, LANES>,
+) where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    assert_eq!(first_input.len(), output.len(), "slice lengths differ");
+    assert_eq!(second_input.len(), output.len(), "slice lengths differ");
+    for range in vector_ranges::<T, LANES>(first_input) {
+        let result = f(
+            load(&first_input[range.clone()]),
+            load(&second_input[range.clone()]),
+        );
+        store(result, &mut output[range]);
+    }
+}
+
+#[inline(always)]
+pub(super) fn map2_in_place<T, const LANES: usize>(
+    data: &mut [T],
+    second_input: &[T],
+    f: impl Fn(Simd<T, LANES>, Simd<T, LANES>) -> Simd<T, LANES>,
+) where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    assert_eq!(second_input.len(), data.len(), "slice lengths differ");
+    for range in vector_ranges::<T, LANES>(data) {
+        let result = f(
+            load(&data[range.clone()]),
+            load(&second_input[range.clone()]),
+        );
+        store(result, &mut data[range]);
+    }
+}
+
+#[inline(always)]
+pub(super) fn map2_checked<T, M, const LANES: usize>(
+    first_input: &[T],
+    second_input: &[T],
+    output: &mut [Option<T>],
+    f: impl Fn(Simd<T, LANES>, Simd<T, LANES>) -> (Simd<T, LANES>, Mask<M, LANES>),
+) where
+    T: SimdElement,
+    M: MaskElement,
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    assert_eq!(first_input.len(), output.len(), "slice lengths differ");
+    assert_eq!(second_input.len(), output.len(), "slice lengths differ");
+    for range in vector_ranges::<T, LANES>(first_input) {
+        let result = f(
+            load(&first_input[range.clone()]),
+            load(&second_input[range.clone()]),
+        );
+        store_checked(result, &mut output[range]);
+    }
+}
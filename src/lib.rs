@@ -1,12 +1,25 @@
-#![feature(core_intrinsics, portable_simd)]
+#![cfg_attr(feature = "nightly", feature(core_intrinsics, portable_simd))]
 #![cfg_attr(not(test), no_std)]
 
+// the runtime dispatch uses `std` to detect the CPU features
+#[cfg(all(feature = "runtime-dispatch", not(test)))]
+extern crate std;
+
+mod array;
+/// Functions that run the approximations and integer functions over whole slices, using the widest
+/// SIMD vectors that the target supports.
+#[cfg(feature = "nightly")]
+pub mod batch;
 mod scalar;
 mod shared;
+#[cfg(feature = "nightly")]
 mod simd;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "nightly"))]
 pub mod test;
 
-pub use shared::float::FastApproxFloat;
-pub use shared::int::FastExactInt;
+pub use shared::float::{
+    Accurate, FastApproxFloat, FastApproxFloatChecked, Fastest, Precision, P0, P1, P2, P3, P4, P5,
+    P6, P7,
+};
+pub use shared::int::{FastDivisor, FastExactInt, FastExactSignedInt};
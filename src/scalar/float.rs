@@ -1,43 +1,553 @@
 use crate::shared::float::*;
+use crate::shared::intrinsics::*;
 
-impl FastApproxFloat for f32 {
-    #[inline(always)]
-    unsafe fn sin_fast_approx<const PRECISION: usize>(self) -> f32 {
-        sin_fast_approx::<PRECISION, false>(self)
-    }
-
-    #[inline(always)]
-    unsafe fn cos_fast_approx<const PRECISION: usize>(self) -> f32 {
-        sin_fast_approx::<PRECISION, true>(self)
-    }
-
-    #[inline(always)]
-    unsafe fn sin_restrict_fast_approx<const PRECISION: usize>(self) -> f32 {
-        sin_restrict_fast_approx::<PRECISION>(self)
-    }
-
-    #[inline(always)]
-    unsafe fn cos_restrict_fast_approx<const PRECISION: usize>(self) -> f32 {
-        cos_restrict_fast_approx::<PRECISION>(self)
-    }
-
-    #[inline(always)]
-    unsafe fn log2_fast_approx<const PRECISION: usize>(self) -> Self {
-        log2_fast_approx::<PRECISION>(self)
-    }
-
-    #[inline(always)]
-    unsafe fn log10_fast_approx<const PRECISION: usize>(self) -> Self {
-        log10_fast_approx::<PRECISION>(self)
-    }
-
-    #[inline(always)]
-    unsafe fn ln_fast_approx<const PRECISION: usize>(self) -> Self {
-        ln_fast_approx::<PRECISION>(self)
-    }
-
-    #[inline(always)]
-    unsafe fn log_fast_approx<const PRECISION: usize>(self, base: Self) -> Self {
-        log_fast_approx::<PRECISION>(self, base)
-    }
+macro_rules! float_impl {
+    ($f:ty,$kernels:ident) => {
+        impl FastApproxFloat for $f {
+            type Scalar = $f;
+
+            #[inline(always)]
+            unsafe fn sin_fast_approx<P: Precision<$f>>(self) -> Self {
+                $kernels::sin_fast_approx::<P, false>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn cos_fast_approx<P: Precision<$f>>(self) -> Self {
+                $kernels::sin_fast_approx::<P, true>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn sincos_fast_approx<P: Precision<$f>>(self) -> (Self, Self) {
+                $kernels::sincos_fast_approx::<P>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn sin_wide_fast_approx<P: Precision<$f>>(self) -> Self {
+                $kernels::sin_wide_fast_approx::<P, false>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn cos_wide_fast_approx<P: Precision<$f>>(self) -> Self {
+                $kernels::sin_wide_fast_approx::<P, true>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn sincos_wide_fast_approx<P: Precision<$f>>(self) -> (Self, Self) {
+                $kernels::sincos_wide_fast_approx::<P>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn sin_restrict_fast_approx<P: Precision<$f>>(self) -> Self {
+                $kernels::sin_restrict_fast_approx::<P>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn cos_restrict_fast_approx<P: Precision<$f>>(self) -> Self {
+                $kernels::cos_restrict_fast_approx::<P>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn tan_fast_approx<P: Precision<$f>>(self) -> Self {
+                $kernels::tan_fast_approx::<P>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn tan_restrict_fast_approx<P: Precision<$f>>(self) -> Self {
+                $kernels::tan_restrict_fast_approx::<P>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn atan_fast_approx<P: Precision<$f>>(self) -> Self {
+                $kernels::atan_fast_approx::<P>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn atan2_fast_approx<P: Precision<$f>>(self, x: Self) -> Self {
+                $kernels::atan2_fast_approx::<P>(self, x)
+            }
+
+            #[inline(always)]
+            unsafe fn asin_fast_approx<P: Precision<$f>>(self) -> Self {
+                $kernels::asin_fast_approx::<P>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn acos_fast_approx<P: Precision<$f>>(self) -> Self {
+                $kernels::acos_fast_approx::<P>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn log2_fast_approx<P: Precision<$f>>(self) -> Self {
+                $kernels::log2_fast_approx::<P>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn log10_fast_approx<P: Precision<$f>>(self) -> Self {
+                $kernels::log10_fast_approx::<P>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn ln_fast_approx<P: Precision<$f>>(self) -> Self {
+                $kernels::ln_fast_approx::<P>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn log_fast_approx<P: Precision<$f>>(self, base: Self) -> Self {
+                $kernels::log_fast_approx::<P>(self, base)
+            }
+
+            #[inline(always)]
+            unsafe fn exp2_fast_approx<P: Precision<$f>>(self) -> Self {
+                $kernels::exp2_fast_approx::<P>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn exp_fast_approx<P: Precision<$f>>(self) -> Self {
+                $kernels::exp_fast_approx::<P>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn exp10_fast_approx<P: Precision<$f>>(self) -> Self {
+                $kernels::exp10_fast_approx::<P>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn pow_fast_approx<P: Precision<$f>>(self, exponent: Self) -> Self {
+                $kernels::pow_fast_approx::<P>(self, exponent)
+            }
+
+            #[inline(always)]
+            unsafe fn powi_const<const EXPONENT: i32>(self) -> Self {
+                $kernels::powi_const::<EXPONENT>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn powf_const<P: Precision<$f>, const NUMERATOR: i32, const DENOMINATOR: u32>(
+                self,
+            ) -> Self {
+                $kernels::powf_const::<P, NUMERATOR, DENOMINATOR>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn sinh_fast_approx<P: Precision<$f>>(self) -> Self {
+                $kernels::sinh_fast_approx::<P>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn cosh_fast_approx<P: Precision<$f>>(self) -> Self {
+                $kernels::cosh_fast_approx::<P>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn tanh_fast_approx<P: Precision<$f>>(self) -> Self {
+                $kernels::tanh_fast_approx::<P>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn sqrt_fast_approx<P: Precision<$f>>(self) -> Self {
+                $kernels::sqrt_fast_approx::<P>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn rsqrt_fast_approx<P: Precision<$f>>(self) -> Self {
+                $kernels::rsqrt_fast_approx::<P>(self)
+            }
+
+            #[inline(always)]
+            unsafe fn cbrt_fast_approx<P: Precision<$f>>(self) -> Self {
+                $kernels::cbrt_fast_approx::<P>(self)
+            }
+        }
+    };
+}
+
+float_impl!(f32, single);
+float_impl!(f64, double);
+
+// the fast path is taken whenever the input is in the valid range of the unsafe function. NaNs
+// are never in range, so they always reach the slow path and propagate from there.
+macro_rules! checked_impl {
+    (
+        $f:ident,
+        $kernels:ident,
+        $exp:ident,
+        $exp2:ident,
+        $ln:ident,
+        $log2:ident,
+        $log10:ident,
+        $pow:ident,
+        $powi:ident
+    ) => {
+        impl FastApproxFloatChecked for $f {
+            // past the checked bound, the error of the fast range reduction would be larger than
+            // the error of the polynomial, so the wide reduction is used instead
+            #[inline(always)]
+            fn sin_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                let max = $kernels::checked_trig_max::<P>();
+                if (-max..=max).contains(&self) {
+                    unsafe { self.sin_fast_approx::<P>() }
+                } else {
+                    self.sin_wide_fast_approx_checked::<P>()
+                }
+            }
+
+            #[inline(always)]
+            fn cos_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                let max = $kernels::checked_trig_max::<P>();
+                if (-max..=max).contains(&self) {
+                    unsafe { self.cos_fast_approx::<P>() }
+                } else {
+                    self.cos_wide_fast_approx_checked::<P>()
+                }
+            }
+
+            #[inline(always)]
+            fn sincos_fast_approx_checked<P: Precision<$f>>(self) -> (Self, Self) {
+                let max = $kernels::checked_trig_max::<P>();
+                if (-max..=max).contains(&self) {
+                    unsafe { self.sincos_fast_approx::<P>() }
+                } else {
+                    self.sincos_wide_fast_approx_checked::<P>()
+                }
+            }
+
+            #[inline(always)]
+            fn sin_wide_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                if self.is_finite() {
+                    unsafe { self.sin_wide_fast_approx::<P>() }
+                } else {
+                    Self::NAN
+                }
+            }
+
+            #[inline(always)]
+            fn cos_wide_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                if self.is_finite() {
+                    unsafe { self.cos_wide_fast_approx::<P>() }
+                } else {
+                    Self::NAN
+                }
+            }
+
+            #[inline(always)]
+            fn sincos_wide_fast_approx_checked<P: Precision<$f>>(self) -> (Self, Self) {
+                if self.is_finite() {
+                    unsafe { self.sincos_wide_fast_approx::<P>() }
+                } else {
+                    (Self::NAN, Self::NAN)
+                }
+            }
+
+            #[inline(always)]
+            fn sin_restrict_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                if (-core::$f::consts::FRAC_PI_2..=core::$f::consts::FRAC_PI_2).contains(&self) {
+                    unsafe { self.sin_restrict_fast_approx::<P>() }
+                } else {
+                    self.sin_fast_approx_checked::<P>()
+                }
+            }
+
+            #[inline(always)]
+            fn cos_restrict_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                if (-core::$f::consts::FRAC_PI_2..=core::$f::consts::FRAC_PI_2).contains(&self) {
+                    unsafe { self.cos_restrict_fast_approx::<P>() }
+                } else {
+                    self.cos_fast_approx_checked::<P>()
+                }
+            }
+
+            #[inline(always)]
+            fn tan_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                let max = $kernels::checked_trig_max::<P>();
+                if (-max..=max).contains(&self) {
+                    unsafe { self.tan_fast_approx::<P>() }
+                } else {
+                    let (sin, cos) = self.sincos_wide_fast_approx_checked::<P>();
+                    sin / cos
+                }
+            }
+
+            #[inline(always)]
+            fn tan_restrict_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                if (-core::$f::consts::FRAC_PI_2..=core::$f::consts::FRAC_PI_2).contains(&self) {
+                    unsafe { self.tan_restrict_fast_approx::<P>() }
+                } else {
+                    self.tan_fast_approx_checked::<P>()
+                }
+            }
+
+            #[inline(always)]
+            fn atan_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                if (-<$f>::MAX..=<$f>::MAX).contains(&self) {
+                    unsafe { self.atan_fast_approx::<P>() }
+                } else if self.is_infinite() {
+                    if self.is_sign_negative() {
+                        -core::$f::consts::FRAC_PI_2
+                    } else {
+                        core::$f::consts::FRAC_PI_2
+                    }
+                } else {
+                    self
+                }
+            }
+
+            #[inline(always)]
+            fn atan2_fast_approx_checked<P: Precision<$f>>(self, x: Self) -> Self {
+                if self.is_nan() || x.is_nan() {
+                    self + x
+                } else if self.is_infinite() {
+                    // the angle only depends on the signs, so the infinities are shrunk to values
+                    // that the fast path is valid for
+                    let y = if self.is_sign_negative() { -1.0 } else { 1.0 };
+                    let x = if !x.is_infinite() {
+                        0.0
+                    } else if x.is_sign_negative() {
+                        -1.0
+                    } else {
+                        1.0
+                    };
+                    unsafe { y.atan2_fast_approx::<P>(x) }
+                } else if x.is_infinite() || (self == 0.0 && x == 0.0) {
+                    let angle = if x.is_sign_negative() {
+                        core::$f::consts::PI
+                    } else {
+                        0.0
+                    };
+                    if self.is_sign_negative() {
+                        -angle
+                    } else {
+                        angle
+                    }
+                } else if (-<$f>::MIN_POSITIVE..<$f>::MIN_POSITIVE).contains(&self)
+                    && (-<$f>::MIN_POSITIVE..<$f>::MIN_POSITIVE).contains(&x)
+                {
+                    // the fast path doesn't handle subnormals, so both sides are scaled by the same
+                    // power of 2, which doesn't change the angle
+                    let scale = (1_u64 << <$f>::MANTISSA_DIGITS) as $f;
+                    unsafe { (self * scale).atan2_fast_approx::<P>(x * scale) }
+                } else {
+                    unsafe { self.atan2_fast_approx::<P>(x) }
+                }
+            }
+
+            #[inline(always)]
+            fn asin_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                if (-1.0..=1.0).contains(&self) {
+                    unsafe { self.asin_fast_approx::<P>() }
+                } else {
+                    <$f>::NAN
+                }
+            }
+
+            #[inline(always)]
+            fn acos_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                if (-1.0..=1.0).contains(&self) {
+                    unsafe { self.acos_fast_approx::<P>() }
+                } else {
+                    <$f>::NAN
+                }
+            }
+
+            #[inline(always)]
+            fn log2_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                if (<$f>::MIN_POSITIVE..=<$f>::MAX).contains(&self) {
+                    unsafe { self.log2_fast_approx::<P>() }
+                } else {
+                    unsafe { $log2(self) }
+                }
+            }
+
+            #[inline(always)]
+            fn log10_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                if (<$f>::MIN_POSITIVE..=<$f>::MAX).contains(&self) {
+                    unsafe { self.log10_fast_approx::<P>() }
+                } else {
+                    unsafe { $log10(self) }
+                }
+            }
+
+            #[inline(always)]
+            fn ln_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                if (<$f>::MIN_POSITIVE..=<$f>::MAX).contains(&self) {
+                    unsafe { self.ln_fast_approx::<P>() }
+                } else {
+                    unsafe { $ln(self) }
+                }
+            }
+
+            #[inline(always)]
+            fn log_fast_approx_checked<P: Precision<$f>>(self, base: Self) -> Self {
+                let range = <$f>::MIN_POSITIVE..=<$f>::MAX;
+                if range.contains(&self) && range.contains(&base) {
+                    unsafe { self.log_fast_approx::<P>(base) }
+                } else {
+                    unsafe { $log2(self) / $log2(base) }
+                }
+            }
+
+            #[inline(always)]
+            fn exp2_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                if ($kernels::EXP2_BOUNDS.0..=$kernels::EXP2_BOUNDS.1).contains(&self) {
+                    unsafe { self.exp2_fast_approx::<P>() }
+                } else {
+                    unsafe { $exp2(self) }
+                }
+            }
+
+            #[inline(always)]
+            fn exp_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                if ($kernels::EXP_BOUNDS.0..=$kernels::EXP_BOUNDS.1).contains(&self) {
+                    unsafe { self.exp_fast_approx::<P>() }
+                } else {
+                    unsafe { $exp(self) }
+                }
+            }
+
+            #[inline(always)]
+            fn exp10_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                if ($kernels::EXP10_BOUNDS.0..=$kernels::EXP10_BOUNDS.1).contains(&self) {
+                    unsafe { self.exp10_fast_approx::<P>() }
+                } else {
+                    unsafe { $pow(10.0, self) }
+                }
+            }
+
+            #[inline(always)]
+            fn pow_fast_approx_checked<P: Precision<$f>>(self, exponent: Self) -> Self {
+                // the fast path is exp2(log2(self) * exponent), so the product has to be in the
+                // valid range of exp2
+                if (<$f>::MIN_POSITIVE..=<$f>::MAX).contains(&self)
+                    && (-<$f>::MAX..=<$f>::MAX).contains(&exponent)
+                    && ($kernels::EXP2_BOUNDS.0..=$kernels::EXP2_BOUNDS.1)
+                        .contains(&(unsafe { self.log2_fast_approx::<P>() } * exponent))
+                {
+                    unsafe { self.pow_fast_approx::<P>(exponent) }
+                } else {
+                    unsafe { $pow(self, exponent) }
+                }
+            }
+
+            #[inline(always)]
+            fn powi_const_checked<const EXPONENT: i32>(self) -> Self {
+                // the magnitude of the input is between 2^exponent and 2^(exponent + 1), so the
+                // magnitude of the result's exponent is at most the larger of those times EXPONENT
+                let exponent =
+                    ((self.to_bits() << 1) >> <$f>::MANTISSA_DIGITS) as i32 - (<$f>::MAX_EXP - 1);
+                let max_exponent = exponent.unsigned_abs().max((exponent + 1).unsigned_abs())
+                    as u64
+                    * EXPONENT.unsigned_abs() as u64;
+
+                if EXPONENT == 0 {
+                    1.0
+                } else if self.is_normal() && max_exponent <= -$kernels::EXP2_BOUNDS.0 as u64 {
+                    unsafe { self.powi_const::<EXPONENT>() }
+                } else {
+                    unsafe { $powi(self, EXPONENT) }
+                }
+            }
+
+            #[inline(always)]
+            fn powf_const_checked<
+                P: Precision<$f>,
+                const NUMERATOR: i32,
+                const DENOMINATOR: u32,
+            >(
+                self,
+            ) -> Self {
+                if DENOMINATOR == 0 {
+                    panic!("invalid denominator: {:?}", DENOMINATOR);
+                } else if DENOMINATOR == 1 {
+                    self.powi_const_checked::<NUMERATOR>()
+                } else {
+                    self.pow_fast_approx_checked::<P>(NUMERATOR as $f / DENOMINATOR as $f)
+                }
+            }
+
+            #[inline(always)]
+            fn sinh_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                if (-$kernels::HYPERBOLIC_MAX..=$kernels::HYPERBOLIC_MAX).contains(&self) {
+                    unsafe { self.sinh_fast_approx::<P>() }
+                } else if self.is_nan() {
+                    self
+                } else {
+                    // e^-x is negligible here. e^x is split into two halves, so that it doesn't
+                    // overflow before being divided by 2.
+                    let sqrt_exp = unsafe { $exp(if self < 0.0 { -self } else { self } * 0.5) };
+                    let half_exp = sqrt_exp * (sqrt_exp * 0.5);
+                    if self < 0.0 {
+                        -half_exp
+                    } else {
+                        half_exp
+                    }
+                }
+            }
+
+            #[inline(always)]
+            fn cosh_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                if (-$kernels::HYPERBOLIC_MAX..=$kernels::HYPERBOLIC_MAX).contains(&self) {
+                    unsafe { self.cosh_fast_approx::<P>() }
+                } else if self.is_nan() {
+                    self
+                } else {
+                    // the same as sinh, but the result is always positive
+                    let sqrt_exp = unsafe { $exp(if self < 0.0 { -self } else { self } * 0.5) };
+                    sqrt_exp * (sqrt_exp * 0.5)
+                }
+            }
+
+            #[inline(always)]
+            fn tanh_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                if (-<$f>::MAX..=<$f>::MAX).contains(&self) {
+                    unsafe { self.tanh_fast_approx::<P>() }
+                } else if self.is_infinite() {
+                    if self.is_sign_negative() {
+                        -1.0
+                    } else {
+                        1.0
+                    }
+                } else {
+                    self
+                }
+            }
+
+            #[inline(always)]
+            fn sqrt_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                if (0.0..=<$f>::MAX).contains(&self) {
+                    unsafe { self.sqrt_fast_approx::<P>() }
+                } else if self == <$f>::INFINITY || self.is_nan() {
+                    self
+                } else {
+                    <$f>::NAN
+                }
+            }
+
+            #[inline(always)]
+            fn rsqrt_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                if self > 0.0 && self <= <$f>::MAX {
+                    unsafe { self.rsqrt_fast_approx::<P>() }
+                } else if self == 0.0 {
+                    // keeps the sign of the zero, the same as 1 / sqrt(self)
+                    1.0 / self
+                } else if self == <$f>::INFINITY {
+                    0.0
+                } else if self.is_nan() {
+                    self
+                } else {
+                    <$f>::NAN
+                }
+            }
+
+            #[inline(always)]
+            fn cbrt_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                if self.is_finite() {
+                    unsafe { self.cbrt_fast_approx::<P>() }
+                } else {
+                    self
+                }
+            }
+        }
+    };
 }
+
+checked_impl!(f32, single, expf32, exp2f32, logf32, log2f32, log10f32, powf32, powif32);
+checked_impl!(f64, double, expf64, exp2f64, logf64, log2f64, log10f64, powf64, powif64);
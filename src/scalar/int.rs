@@ -1,12 +1,16 @@
+use crate::shared::float::{FastApproxFloat, P2, P3, P4, P7};
 use crate::shared::int::consts::ilog_mul_shift;
 use crate::shared::int::*;
-use core::intrinsics::*;
+use crate::shared::intrinsics::*;
+use core::ops::{Div, Rem};
 
 // Credit to Duplex (duplexsystem) for creating most of the fast scalar ilog stuff
 
 macro_rules! unsigned_impl {
-    ($u:ty,$s:ty) => {
+    ($u:ty,$s:ty,$f:ty,$root_precision:ty,$log_precision:ty) => {
         impl FastExactInt for $u {
+            type Checked = Option<Self>;
+
             #[inline(always)]
             fn ilog_const_base<const BASE: u32>(self) -> Self {
                 assert!(self > 0, "invalid input: less than 1");
@@ -22,33 +26,444 @@ macro_rules! unsigned_impl {
                 } else {
                     let mul_shift = ilog_mul_shift!($u, BASE);
 
-                    let approx = (((self.leading_zeros() as $s | -(<$u>::BITS as $s))
-                        * -(mul_shift.0 as $s)) as $u)
+                    // the multiplication relies on wrapping
+                    let approx = ((self.leading_zeros() as $s | -(<$u>::BITS as $s))
+                        .wrapping_mul((mul_shift.0 as $s).wrapping_neg())
+                        as $u)
                         >> mul_shift.1;
                     // gets rid of the bounds check in the ipow
-                    assume(approx < <$u>::MAX.ilog(BASE as $u) as $u);
+                    assume(approx <= <$u>::MAX.ilog(BASE as $u) as $u);
                     approx - ((approx.exp_const_coeff::<BASE>() > self) as $u)
                 }
             }
 
+            #[inline(always)]
+            fn checked_ilog_const_base<const BASE: u32>(self) -> Self::Checked {
+                (self > 0).then(|| unsafe { self.ilog_const_base_unchecked::<BASE>() })
+            }
+
+            #[inline(always)]
+            fn ilog_var_base(self, base: Self) -> Self {
+                assert!(self > 0, "invalid input: less than 1");
+                assert!(base > 1, "invalid base: {:?}", base);
+                unsafe { self.ilog_var_base_unchecked(base) }
+            }
+
+            #[inline(always)]
+            unsafe fn ilog_var_base_unchecked(self, base: Self) -> Self {
+                // the log2 approximations are within 1.0e-4 of the real values. the log of the
+                // input is at most BITS, and the log of the base is at least 1, so dividing them
+                // keeps the estimate within (BITS + 1) * 1.0e-4 of the real log
+                const MAX_ESTIMATE_ERROR: $f = (<$u>::BITS + 1) as $f * 1.0e-4;
+
+                // unless the estimate is that close to an integer, flooring it gives the exact
+                // result
+                let estimate =
+                    (self as $f).log2_fast_approx::<P2>() / (base as $f).log2_fast_approx::<P2>();
+                let floor = estimate as $u;
+                let fraction = estimate - floor as $f;
+                if fraction > MAX_ESTIMATE_ERROR && fraction < 1.0 - MAX_ESTIMATE_ERROR {
+                    return floor;
+                }
+
+                // close to a power of the base, subtracting 0.5 and flooring gives either the real
+                // log or 1 less than it
+                let approx = (estimate - 0.5).max(0.0) as $u;
+
+                // the power can't overflow, because it's at most the input. the next power can,
+                // but if it does, it's definitely greater than the input.
+                let power = base.wrapping_pow(approx as u32);
+                approx
+                    + power
+                        .checked_mul(base)
+                        .map_or(0, |next| (next <= self) as $u)
+            }
+
+            #[inline(always)]
+            fn exp_const_coeff<const COEFF: u32>(self) -> Self {
+                match self.checked_exp_const_coeff::<COEFF>() {
+                    Some(result) => result,
+                    None => panic!("overflow from power"),
+                }
+            }
+
+            #[inline(always)]
+            fn checked_exp_const_coeff<const COEFF: u32>(self) -> Self::Checked {
+                // the powers of 0 and 1 never overflow, so there's no largest power to size the
+                // table with
+                match COEFF {
+                    0 => Some((self == 0) as $u),
+                    1 => Some(1),
+                    _ => {
+                        let power_count = <$u>::MAX.ilog(COEFF as $u) as usize + 1;
+                        let mut power_table = [0 as $u; <$u>::BITS as usize];
+                        for i in 0..power_count {
+                            power_table[i] = (COEFF as $u).pow(i as u32);
+                        }
+
+                        // compared before casting, so large exponents can't be truncated into the
+                        // table
+                        if self < power_count as $u {
+                            Some(power_table[self as usize])
+                        } else {
+                            None
+                        }
+                    }
+                }
+            }
+
+            #[inline(always)]
+            fn saturating_exp_const_coeff<const COEFF: u32>(self) -> Self {
+                self.checked_exp_const_coeff::<COEFF>().unwrap_or(<$u>::MAX)
+            }
+
+            #[inline(always)]
+            fn ipow(self, exp: Self) -> Self {
+                match self.checked_ipow(exp) {
+                    Some(result) => result,
+                    None => panic!("overflow from power"),
+                }
+            }
+
+            #[inline(always)]
+            fn wrapping_ipow(self, exp: Self) -> Self {
+                let mut result: $u = 1;
+                let mut square = self;
+                let mut remaining = exp;
+                while remaining != 0 {
+                    if remaining & 1 == 1 {
+                        result = result.wrapping_mul(square);
+                    }
+                    remaining >>= 1;
+                    square = square.wrapping_mul(square);
+                }
+
+                result
+            }
+
+            #[inline(always)]
+            fn checked_ipow(self, exp: Self) -> Self::Checked {
+                let mut result: $u = 1;
+                let mut square = self;
+                let mut remaining = exp;
+                let mut overflow = false;
+                while remaining != 0 {
+                    if remaining & 1 == 1 {
+                        let (product, product_overflow) = result.overflowing_mul(square);
+                        result = product;
+                        overflow |= product_overflow;
+                    }
+                    remaining >>= 1;
+                    // the square only matters if there are bits left to multiply it into
+                    if remaining != 0 {
+                        let (product, product_overflow) = square.overflowing_mul(square);
+                        square = product;
+                        overflow |= product_overflow;
+                    }
+                }
+
+                (!overflow).then_some(result)
+            }
+
+            #[inline(always)]
+            fn saturating_ipow(self, exp: Self) -> Self {
+                self.checked_ipow(exp).unwrap_or(<$u>::MAX)
+            }
+
+            #[inline(always)]
+            fn isqrt(self) -> Self {
+                self.iroot_const::<2>()
+            }
+
+            #[inline(always)]
+            fn icbrt(self) -> Self {
+                self.iroot_const::<3>()
+            }
+
+            #[inline(always)]
+            fn iroot_const<const N: u32>(self) -> Self {
+                if N == 0 {
+                    panic!("invalid root: {:?}", N);
+                } else if N == 1 || self < 2 {
+                    return self;
+                }
+
+                let x = self as $f;
+                let estimate = unsafe {
+                    match N {
+                        2 => x.sqrt_fast_approx::<$root_precision>(),
+                        3 => x.cbrt_fast_approx::<$root_precision>(),
+                        _ => (x.log2_fast_approx::<$log_precision>() / N as $f)
+                            .exp2_fast_approx::<$log_precision>(),
+                    }
+                };
+                let mut root = (estimate as $u).max(1);
+
+                // the float estimate can be off by more than 1 when the root has more bits than
+                // the mantissa, so a newton step is done in integer arithmetic first. the estimate
+                // is already relatively close, so the step can't overshoot by a whole integer.
+                if <$u>::BITS > 64 && root >> 32 != 0 {
+                    root =
+                        ((N - 1) as $u * root + self / root.wrapping_ipow((N - 1) as $u)) / N as $u;
+                }
+
+                // at this point, the estimate is either correct or off by 1
+                if root
+                    .checked_ipow(N as $u)
+                    .map_or(true, |power| power > self)
+                {
+                    root - 1
+                } else {
+                    root + (root + 1)
+                        .checked_ipow(N as $u)
+                        .map_or(0, |power| (power <= self) as $u)
+                }
+            }
+
+            #[inline(always)]
+            fn div_const<const D: u32>(self) -> Self {
+                if D == 0 {
+                    panic!("invalid divisor: {:?}", D);
+                }
+
+                // the compiler already replaces division by a constant with a multiply-high and
+                // shifts for scalar types
+                if D as u128 > <$u>::MAX as u128 {
+                    0
+                } else {
+                    self / D as $u
+                }
+            }
+
+            #[inline(always)]
+            fn rem_const<const D: u32>(self) -> Self {
+                if D == 0 {
+                    panic!("invalid divisor: {:?}", D);
+                }
+
+                if D as u128 > <$u>::MAX as u128 {
+                    self
+                } else {
+                    self % D as $u
+                }
+            }
+        }
+    };
+}
+
+macro_rules! signed_impl {
+    ($s:ty,$u:ty) => {
+        impl FastExactInt for $s {
+            type Checked = Option<Self>;
+
+            #[inline(always)]
+            fn ilog_const_base<const BASE: u32>(self) -> Self {
+                assert!(self > 0, "invalid input: less than 1");
+                unsafe { self.ilog_const_base_unchecked::<BASE>() }
+            }
+
+            #[inline(always)]
+            unsafe fn ilog_const_base_unchecked<const BASE: u32>(self) -> Self {
+                // positive signed values have the same representation as unsigned ones
+                (self as $u).ilog_const_base_unchecked::<BASE>() as $s
+            }
+
+            #[inline(always)]
+            fn checked_ilog_const_base<const BASE: u32>(self) -> Self::Checked {
+                (self > 0).then(|| unsafe { self.ilog_const_base_unchecked::<BASE>() })
+            }
+
+            #[inline(always)]
+            fn ilog_var_base(self, base: Self) -> Self {
+                assert!(self > 0, "invalid input: less than 1");
+                assert!(base > 1, "invalid base: {:?}", base);
+                unsafe { self.ilog_var_base_unchecked(base) }
+            }
+
+            #[inline(always)]
+            unsafe fn ilog_var_base_unchecked(self, base: Self) -> Self {
+                (self as $u).ilog_var_base_unchecked(base as $u) as $s
+            }
+
             #[inline(always)]
             fn exp_const_coeff<const COEFF: u32>(self) -> Self {
-                let power_count = <$u>::MAX.ilog(COEFF as $u) as usize;
-                let mut power_table = [0 as $u; <$u>::BITS as usize];
-                for i in 0..power_count {
-                    power_table[i] = (COEFF as $u).pow(i as u32);
+                assert!(self >= 0, "invalid exponent: less than 0");
+                let result = (self as $u).exp_const_coeff::<COEFF>();
+                assert!(result <= <$s>::MAX as $u, "overflow from power");
+                result as $s
+            }
+
+            #[inline(always)]
+            fn checked_exp_const_coeff<const COEFF: u32>(self) -> Self::Checked {
+                if self < 0 {
+                    return None;
+                }
+
+                let result = (self as $u).checked_exp_const_coeff::<COEFF>()?;
+                (result <= <$s>::MAX as $u).then_some(result as $s)
+            }
+
+            #[inline(always)]
+            fn saturating_exp_const_coeff<const COEFF: u32>(self) -> Self {
+                assert!(self >= 0, "invalid exponent: less than 0");
+                self.checked_exp_const_coeff::<COEFF>().unwrap_or(<$s>::MAX)
+            }
+
+            #[inline(always)]
+            fn ipow(self, exp: Self) -> Self {
+                assert!(exp >= 0, "invalid exponent: less than 0");
+                match self.checked_ipow(exp) {
+                    Some(result) => result,
+                    None => panic!("overflow from power"),
+                }
+            }
+
+            #[inline(always)]
+            fn wrapping_ipow(self, exp: Self) -> Self {
+                assert!(exp >= 0, "invalid exponent: less than 0");
+                // wrapping multiplication is the same for signed and unsigned values
+                (self as $u).wrapping_ipow(exp as $u) as $s
+            }
+
+            #[inline(always)]
+            fn checked_ipow(self, exp: Self) -> Self::Checked {
+                if exp < 0 {
+                    return None;
+                }
+
+                let magnitude = self.unsigned_abs().checked_ipow(exp as $u)?;
+                if self < 0 && exp & 1 == 1 {
+                    // the negative range has room for one more value than the positive range
+                    (magnitude <= <$s>::MIN.unsigned_abs()).then(|| magnitude.wrapping_neg() as $s)
+                } else {
+                    (magnitude <= <$s>::MAX as $u).then_some(magnitude as $s)
+                }
+            }
+
+            #[inline(always)]
+            fn saturating_ipow(self, exp: Self) -> Self {
+                assert!(exp >= 0, "invalid exponent: less than 0");
+                self.checked_ipow(exp)
+                    .unwrap_or(if self < 0 && exp & 1 == 1 {
+                        <$s>::MIN
+                    } else {
+                        <$s>::MAX
+                    })
+            }
+
+            #[inline(always)]
+            fn isqrt(self) -> Self {
+                self.iroot_const::<2>()
+            }
+
+            #[inline(always)]
+            fn icbrt(self) -> Self {
+                self.iroot_const::<3>()
+            }
+
+            #[inline(always)]
+            fn iroot_const<const N: u32>(self) -> Self {
+                assert!(self >= 0, "invalid input: less than 0");
+                (self as $u).iroot_const::<N>() as $s
+            }
+
+            #[inline(always)]
+            fn div_const<const D: u32>(self) -> Self {
+                // the magnitude is divided, so divisors that don't fit in the signed type still
+                // work
+                let quotient = self.unsigned_abs().div_const::<D>() as $s;
+                if self < 0 {
+                    quotient.wrapping_neg()
+                } else {
+                    quotient
                 }
+            }
 
-                let index = self as usize;
-                assert!(index < power_count, "overflow from power");
+            #[inline(always)]
+            fn rem_const<const D: u32>(self) -> Self {
+                let remainder = self.unsigned_abs().rem_const::<D>() as $s;
+                if self < 0 {
+                    remainder.wrapping_neg()
+                } else {
+                    remainder
+                }
+            }
+        }
 
-                power_table[index]
+        impl FastExactSignedInt for $s {
+            #[inline(always)]
+            fn exp_const_signed_coeff<const COEFF: i32>(self) -> Self {
+                assert!(
+                    <$s>::try_from(COEFF).is_ok(),
+                    "invalid coefficient: {:?}",
+                    COEFF
+                );
+                (COEFF as $s).ipow(self)
+            }
+
+            #[inline(always)]
+            fn checked_exp_const_signed_coeff<const COEFF: i32>(self) -> Self::Checked {
+                assert!(
+                    <$s>::try_from(COEFF).is_ok(),
+                    "invalid coefficient: {:?}",
+                    COEFF
+                );
+                (COEFF as $s).checked_ipow(self)
+            }
+
+            #[inline(always)]
+            fn saturating_exp_const_signed_coeff<const COEFF: i32>(self) -> Self {
+                assert!(
+                    <$s>::try_from(COEFF).is_ok(),
+                    "invalid coefficient: {:?}",
+                    COEFF
+                );
+                (COEFF as $s).saturating_ipow(self)
+            }
+        }
+    };
+}
+
+// the last two parameters are the precision levels for the root and log estimates in iroot_const
+unsigned_impl!(u8, i8, f32, P3, P3);
+unsigned_impl!(u16, i16, f32, P3, P3);
+unsigned_impl!(u32, i32, f32, P3, P3);
+unsigned_impl!(u64, i64, f64, P4, P7);
+unsigned_impl!(u128, i128, f64, P4, P7);
+unsigned_impl!(usize, isize, f64, P4, P7);
+
+signed_impl!(i8, u8);
+signed_impl!(i16, u16);
+signed_impl!(i32, u32);
+signed_impl!(i64, u64);
+signed_impl!(i128, u128);
+signed_impl!(isize, usize);
+
+macro_rules! divisor_impl {
+    ($u:ty) => {
+        impl Div<FastDivisor<$u>> for $u {
+            type Output = $u;
+
+            #[inline(always)]
+            fn div(self, rhs: FastDivisor<$u>) -> $u {
+                let high = ((self as u128 * rhs.multiplier as u128) >> <$u>::BITS) as $u;
+                (high + ((self - high) >> rhs.pre_shift)) >> rhs.post_shift
+            }
+        }
+
+        impl Rem<FastDivisor<$u>> for $u {
+            type Output = $u;
+
+            #[inline(always)]
+            fn rem(self, rhs: FastDivisor<$u>) -> $u {
+                self - (self / rhs) * rhs.divisor
             }
         }
     };
 }
 
-unsigned_impl!(u8, i8);
-unsigned_impl!(u16, i16);
-unsigned_impl!(u32, i32);
-unsigned_impl!(u64, i64);
+divisor_impl!(u8);
+divisor_impl!(u16);
+divisor_impl!(u32);
+divisor_impl!(u64);
+divisor_impl!(usize);
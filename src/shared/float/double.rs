@@ -0,0 +1,828 @@
+use crate::shared::float::kernels::float_kernels;
+
+// Coefficients were generated with a Remez exchange, minimizing the absolute error (or the relative
+// error for `tan` and `exp2`) over the same intervals as the `f32` coefficients. Precision levels
+// 0 to 3 use the same polynomial degrees as the `f32` levels, and levels 4 to 7 increase the
+// degree until the error is about 1e-9, 1e-11, 1e-13 and the limit of `f64`.
+
+/// Subtracting half of the input's bits from this gives the initial guess for `1/sqrt(x)`.
+pub(crate) const RSQRT_MAGIC: u64 = 0x5fe6_eb50_c7b5_37a9;
+/// Subtracting a third of the input's bits from this gives the initial guess for `1/cbrt(x)`.
+pub(crate) const RCBRT_MAGIC: u64 = 0x553e_ee6b_b3a2_6daa;
+
+/// The bounds that `exp2` is valid between.
+pub(crate) const EXP2_BOUNDS: (f64, f64) = (-1021.0, 1023.0);
+/// The bounds that `exp` is valid between.
+pub(crate) const EXP_BOUNDS: (f64, f64) = (-708.0, 709.0);
+/// The bounds that `exp10` is valid between.
+pub(crate) const EXP10_BOUNDS: (f64, f64) = (-307.0, 308.0);
+/// The largest magnitude that `sinh` and `cosh` are valid for.
+pub(crate) const HYPERBOLIC_MAX: f64 = 708.0;
+/// The largest magnitude that the Cody-Waite reduction in the `wide` trig functions is used for.
+/// Larger inputs use the Payne-Hanek reduction instead.
+pub(crate) const CODY_WAITE_MAX: f64 = 134_217_728.0;
+/// PI split into parts for the Cody-Waite reduction. The first two parts have enough trailing zeros
+/// that multiplying them by any multiple of PI up to `CODY_WAITE_MAX` is exact.
+#[allow(clippy::approx_constant)]
+pub(crate) const PI_SPLIT: [f64; 3] = [
+    3.141592651605606_f64,
+    1.9841871479187034e-9_f64,
+    1.1442377452219664e-17_f64,
+];
+/// PI in fixed point with 2 integer bits, used by the Payne-Hanek reduction.
+pub(crate) const PI_FIXED: u64 = 0xc90f_daa2_2168_c234;
+
+float_kernels!(f64, u64, i64, u128, i128, nearbyintf64, sqrtf64, 52, 1.0);
+
+/// Coefficients for `cos(x * PI)` between [-0.5, 0.5], in terms of `x^2`.
+#[inline(always)]
+#[allow(clippy::approx_constant)]
+pub(crate) fn cos_pi_fraction_coeffs<P: Precision<f64>>() -> &'static [f64] {
+    match P::LEVEL {
+        0 => &[-4.0_f64, 0.9719952020229361_f64],
+        1 => &[
+            3.583844379097898_f64,
+            -4.891186930566107_f64,
+            0.9994032294739541_f64,
+        ],
+        2 => &[
+            -1.2221270623191083_f64,
+            4.04128382615298_f64,
+            -4.9339380151433_f64,
+            0.9999932952821684_f64,
+        ],
+        3 => &[
+            0.21969679869091155_f64,
+            -1.3318801612921787_f64,
+            4.058411914173812_f64,
+            -4.934792858675598_f64,
+            0.9999999534666701_f64,
+        ],
+        4 => &[
+            -0.02439671660762475_f64,
+            0.2349375589568969_f64,
+            -1.3352120566693009_f64,
+            4.058709164343036_f64,
+            -4.934802137228381_f64,
+            0.9999999997806517_f64,
+        ],
+        5 => &[
+            0.0018400218559090488_f64,
+            -0.0257763407427792_f64,
+            0.23532544342694545_f64,
+            -1.33526231614822_f64,
+            4.058712107589396_f64,
+            -4.934802200250963_f64,
+            0.9999999999992518_f64,
+        ],
+        6 => &[
+            -0.00010041751385014807_f64,
+            0.001927870971164487_f64,
+            -0.025806532291660807_f64,
+            0.23533058836700127_f64,
+            -1.3352627661864733_f64,
+            4.058712126333491_f64,
+            -4.934802200543689_f64,
+            0.9999999999999981_f64,
+        ],
+        7 => &[
+            4.149591700195007e-06_f64,
+            -0.00010456658073463612_f64,
+            0.0019295562835919126_f64,
+            -0.02580688873935254_f64,
+            0.2353306301298083_f64,
+            -1.3352627688434646_f64,
+            4.058712126416498_f64,
+            -4.934802200544676_f64,
+            1.0_f64,
+        ],
+        _ => unreachable!(),
+    }
+}
+
+/// Coefficients for `sin(x * PI) / x` between [-0.5, 0.5], in terms of `x^2`.
+#[inline(always)]
+#[allow(clippy::approx_constant)]
+pub(crate) fn sin_pi_fraction_coeffs<P: Precision<f64>>() -> &'static [f64] {
+    match P::LEVEL {
+        0 => &[-4.420463367182079_f64, 3.0961323721149143_f64],
+        1 => &[
+            2.2995473391224506_f64,
+            -5.136905337318914_f64,
+            3.140640038419187_f64,
+        ],
+        2 => &[
+            -0.5546361974380873_f64,
+            2.541899027739502_f64,
+            -5.1671427963875125_f64,
+            3.1415820221512356_f64,
+        ],
+        3 => &[
+            0.07722012903462953_f64,
+            -0.5980451741687168_f64,
+            2.5500313772894305_f64,
+            -5.167706878927035_f64,
+            3.1415925800447386_f64,
+        ],
+        4 => &[
+            -0.007000500331558452_f64,
+            0.0820312301276342_f64,
+            -0.599247404902599_f64,
+            2.5501627947211394_f64,
+            -5.167712741221584_f64,
+            3.1415926532437526_f64,
+        ],
+        5 => &[
+            0.0004461772295220842_f64,
+            -0.007362936964038544_f64,
+            0.08214446069130282_f64,
+            -0.5992643838258777_f64,
+            2.5501640322517196_f64,
+            -5.167712779873692_f64,
+            3.141592653588614_f64,
+        ],
+        6 => &[
+            -2.1085689768538008e-05_f64,
+            0.00046594206888549286_f64,
+            -0.007370347469057836_f64,
+            0.08214587559646458_f64,
+            -0.5992645284958557_f64,
+            2.5501640398445717_f64,
+            -5.167712780049385_f64,
+            3.14159265358979_f64,
+        ],
+        7 => &[
+            7.683940330344395e-07_f64,
+            -2.1902021512061458e-05_f64,
+            0.000466299170566909_f64,
+            -0.007370430356022952_f64,
+            0.08214588655328252_f64,
+            -0.5992645293174579_f64,
+            2.550164039877241_f64,
+            -5.1677127800499685_f64,
+            3.141592653589793_f64,
+        ],
+        _ => unreachable!(),
+    }
+}
+
+/// Coefficients for `cos(x)` between [-PI/2, PI/2], in terms of `x^2`.
+#[inline(always)]
+#[allow(clippy::approx_constant)]
+pub(crate) fn cos_restrict_coeffs<P: Precision<f64>>() -> &'static [f64] {
+    match P::LEVEL {
+        0 => &[-0.4052847345693511_f64, 0.9719952020229361_f64],
+        1 => &[
+            0.03679168279936922_f64,
+            -0.49558084922089096_f64,
+            0.9994032294739541_f64,
+        ],
+        2 => &[
+            -0.0012712094856965755_f64,
+            0.041487748045429304_f64,
+            -0.4999124397122459_f64,
+            0.9999932952821684_f64,
+        ],
+        3 => &[
+            2.3153931659053924e-05_f64,
+            -0.00138537043082319_f64,
+            0.04166358469310784_f64,
+            -0.4999990534707673_f64,
+            0.9999999534666701_f64,
+        ],
+        4 => &[
+            -2.605149521548272e-07_f64,
+            2.4760161352583123e-05_f64,
+            -0.001388836140027525_f64,
+            0.041666636258070294_f64,
+            -0.4999999935847177_f64,
+            0.9999999997806517_f64,
+        ],
+        5 => &[
+            1.9907856852657765e-09_f64,
+            -2.752469638981237e-07_f64,
+            2.4801040648797847e-05_f64,
+            -0.0013888884180011647_f64,
+            0.0416666664733852_f64,
+            -0.4999999999702403_f64,
+            0.9999999999992518_f64,
+        ],
+        6 => &[
+            -1.100807163351441e-11_f64,
+            2.0858327959836862e-09_f64,
+            -2.7556935768727107e-07_f64,
+            2.480158287604518e-05_f64,
+            -0.0013888888861136172_f64,
+            0.041666666665811744_f64,
+            -0.49999999999989964_f64,
+            0.9999999999999981_f64,
+        ],
+        7 => &[
+            4.609007376852587e-14_f64,
+            -1.1462904899634448e-11_f64,
+            2.0876561960138396e-09_f64,
+            -2.755731639353551e-07_f64,
+            2.4801587277443952e-05_f64,
+            -0.0013888888888773173_f64,
+            0.04166666666666389_f64,
+            -0.4999999999999997_f64,
+            1.0_f64,
+        ],
+        _ => unreachable!(),
+    }
+}
+
+/// Coefficients for `sin(x) / x` between [-PI/2, PI/2], in terms of `x^2`.
+#[inline(always)]
+#[allow(clippy::approx_constant)]
+pub(crate) fn sin_restrict_coeffs<P: Precision<f64>>() -> &'static [f64] {
+    match P::LEVEL {
+        0 => &[-0.1425667264973698_f64, 0.9855295429778482_f64],
+        1 => &[
+            0.007514377190264498_f64,
+            -0.1656730793666272_f64,
+            0.9996967731734673_f64,
+        ],
+        2 => &[
+            -0.00018363653976946788_f64,
+            0.008306325227159895_f64,
+            -0.16664828381895058_f64,
+            0.9999966159080028_f64,
+        ],
+        3 => &[
+            2.590488500536058e-06_f64,
+            -0.00019800897762795434_f64,
+            0.008332899823351751_f64,
+            -0.1666664763463971_f64,
+            0.999999976589882_f64,
+        ],
+        4 => &[
+            -2.379471354488387e-08_f64,
+            2.751885563865656e-06_f64,
+            -0.00019840702862605004_f64,
+            0.008333329264457142_f64,
+            -0.16666666541439165_f64,
+            0.9999999998898519_f64,
+        ],
+        5 => &[
+            1.5365937324978524e-10_f64,
+            -2.50266363278967e-08_f64,
+            2.755684087346297e-06_f64,
+            -0.00019841265024025175_f64,
+            0.00833333330841459_f64,
+            -0.16666666666098143_f64,
+            0.9999999999996247_f64,
+        ],
+        6 => &[
+            -7.357660119067464e-13_f64,
+            1.604662038722649e-10_f64,
+            -2.5051824648132183e-08_f64,
+            2.7557315528918315e-06_f64,
+            -0.00019841269813956718_f64,
+            0.008333333333226237_f64,
+            -0.1666666666666478_f64,
+            0.999999999999999_f64,
+        ],
+        7 => &[
+            2.7166653768932185e-15_f64,
+            -7.642511673766517e-13_f64,
+            1.6058918643819324e-10_f64,
+            -2.505210638107953e-08_f64,
+            2.7557319204580503e-06_f64,
+            -0.0001984126984115945_f64,
+            0.008333333333332993_f64,
+            -0.16666666666666663_f64,
+            1.0_f64,
+        ],
+        _ => unreachable!(),
+    }
+}
+
+/// Coefficients for `tan(x * PI/2) / x` between [-0.5, 0.5], in terms of `x^2`.
+#[inline(always)]
+#[allow(clippy::approx_constant)]
+pub(crate) fn tan_half_pi_fraction_coeffs<P: Precision<f64>>() -> &'static [f64] {
+    match P::LEVEL {
+        0 => &[
+            1.935239345558613_f64,
+            1.224221955776197_f64,
+            1.5717622281530381_f64,
+        ],
+        1 => &[
+            2.221109088078022_f64,
+            1.109090598570484_f64,
+            1.3006469364532445_f64,
+            1.5707269840504696_f64,
+        ],
+        2 => &[
+            2.551278831703629_f64,
+            0.9532416916798899_f64,
+            1.305530064011534_f64,
+            1.2909455696471808_f64,
+            1.570801305339183_f64,
+        ],
+        3 => &[
+            2.930753160457857_f64,
+            0.7284860283588367_f64,
+            1.3495072045258243_f64,
+            1.2704367329339559_f64,
+            1.2920300699075429_f64,
+            1.570795969351785_f64,
+        ],
+        4 => &[
+            4.442766805928714_f64,
+            -0.5617479031054955_f64,
+            1.7875204844200872_f64,
+            1.192696820972146_f64,
+            1.2805138532410862_f64,
+            1.2730749729806814_f64,
+            1.2750910004340725_f64,
+            1.2919281098288038_f64,
+            1.570796326927185_f64,
+        ],
+        5 => &[
+            5.103620881016026_f64,
+            -1.2832595980425803_f64,
+            2.1141382238936948_f64,
+            1.1134583453411202_f64,
+            1.2916069745138912_f64,
+            1.2719935328527172_f64,
+            1.2734869382585907_f64,
+            1.275081032352455_f64,
+            1.2919282025686458_f64,
+            1.5707963267853988_f64,
+        ],
+        6 => &[
+            6.734853928337893_f64,
+            -3.3771319723805013_f64,
+            3.265835405699631_f64,
+            0.7586485354089137_f64,
+            1.3587876499394274_f64,
+            1.2638948905019811_f64,
+            1.2739073427344054_f64,
+            1.2732314140381504_f64,
+            1.2734379096793804_f64,
+            1.275082009312678_f64,
+            1.291928195068641_f64,
+            1.5707963267948477_f64,
+        ],
+        7 => &[
+            10.209461683539235_f64,
+            -8.947990934327198_f64,
+            7.229444178809641_f64,
+            -0.8914418344953159_f64,
+            1.805087038527911_f64,
+            1.1815428391901697_f64,
+            1.2845001696770797_f64,
+            1.272255002591751_f64,
+            1.2733002656237355_f64,
+            1.27323941242719_f64,
+            1.273261311512425_f64,
+            1.2734371236770374_f64,
+            1.2750820199483281_f64,
+            1.29192819501246_f64,
+            1.5707963267948966_f64,
+        ],
+        _ => unreachable!(),
+    }
+}
+
+/// Coefficients for `atan(x) / x` between [0, 1], in terms of `x^2`.
+#[inline(always)]
+#[allow(clippy::approx_constant)]
+pub(crate) fn atan_restrict_coeffs<P: Precision<f64>>() -> &'static [f64] {
+    match P::LEVEL {
+        0 => &[
+            -0.038986511689184065_f64,
+            0.14626445883427444_f64,
+            -0.32117496653848304_f64,
+            0.9992138121081539_f64,
+        ],
+        1 => &[
+            0.020845114175442093_f64,
+            -0.08515635083372314_f64,
+            0.18015929463654204_f64,
+            -0.3303047855048677_f64,
+            0.9998663294659207_f64,
+        ],
+        2 => &[
+            -0.011719125203206225_f64,
+            0.052647320614738986_f64,
+            -0.11642644834715246_f64,
+            0.19354035942918735_f64,
+            -0.33262282427382533_f64,
+            0.9999772188235485_f64,
+        ],
+        3 => &[
+            0.006811791816736416_f64,
+            -0.03360421560306316_f64,
+            0.07962366584646836_f64,
+            -0.13233341674634203_f64,
+            0.19807815427498132_f64,
+            -0.3331736803402641_f64,
+            0.9999961115390404_f64,
+        ],
+        4 => &[
+            -0.001509301495418407_f64,
+            0.00956733200033257_f64,
+            -0.02849075883617767_f64,
+            0.055028081213494714_f64,
+            -0.0821376033047068_f64,
+            0.10878009352553274_f64,
+            -0.14247222533397375_f64,
+            0.19996436793078184_f64,
+            -0.33333180375646987_f64,
+            0.9999999805600965_f64,
+        ],
+        5 => &[
+            0.0003711797790270474_f64,
+            -0.0028928312935101773_f64,
+            0.01058852752971971_f64,
+            -0.024658725961013844_f64,
+            0.04226122864842347_f64,
+            -0.059269176409014405_f64,
+            0.07450822806746493_f64,
+            -0.0903522520338299_f64,
+            0.11102438707984989_f64,
+            -0.1428486031111001_f64,
+            0.19999952201274673_f64,
+            -0.33333332081020434_f64,
+            0.9999999999024045_f64,
+        ],
+        6 => &[
+            0.0001507858653860425_f64,
+            -0.0013230786985553393_f64,
+            0.005466347536022265_f64,
+            -0.014321519684584805_f64,
+            0.027248409391991718_f64,
+            -0.04129820519255899_f64,
+            0.05393994778038814_f64,
+            -0.06507539945132744_f64,
+            0.0765409941143636_f64,
+            -0.09084366155803145_f64,
+            0.11110347552590344_f64,
+            -0.14285657525795176_f64,
+            0.1999999758811595_f64,
+            -0.33333333285180144_f64,
+            0.999999999997135_f64,
+        ],
+        7 => &[
+            2.6236101631644938e-05_f64,
+            -0.00028202382183289677_f64,
+            0.0014364271630309224_f64,
+            -0.004642292153791113_f64,
+            0.010795288298344037_f64,
+            -0.01952003720228568_f64,
+            0.029153378432220448_f64,
+            -0.03797124409047261_f64,
+            0.04536362954681267_f64,
+            -0.05189753746840083_f64,
+            0.058636729082018495_f64,
+            -0.06663021452816582_f64,
+            0.07691775793060061_f64,
+            -0.0909085298737791_f64,
+            0.11111107031547475_f64,
+            -0.1428571409499517_f64,
+            0.1999999999486785_f64,
+            -0.33333333333268145_f64,
+            0.9999999999999976_f64,
+        ],
+        _ => unreachable!(),
+    }
+}
+
+/// Coefficients for `acos(x) / sqrt(1 - x)` between [0, 1].
+#[inline(always)]
+#[allow(clippy::approx_constant)]
+pub(crate) fn acos_coeffs<P: Precision<f64>>() -> &'static [f64] {
+    match P::LEVEL {
+        0 => &[
+            -0.020892037196937063_f64,
+            0.07689738745258155_f64,
+            -0.21287518418397888_f64,
+            1.5707583404840257_f64,
+        ],
+        1 => &[
+            0.009732970353248644_f64,
+            -0.037618218952183015_f64,
+            0.08563837867931487_f64,
+            -0.21428061110415006_f64,
+            1.5707915339910379_f64,
+        ],
+        2 => &[
+            -0.0049111765516766295_f64,
+            0.020620065848821093_f64,
+            -0.04592723157400081_f64,
+            0.08817105432552953_f64,
+            -0.2145428168465865_f64,
+            1.5707956895157655_f64,
+        ],
+        3 => &[
+            0.0026117222719157377_f64,
+            -0.012003399392613377_f64,
+            0.02776291758718261_f64,
+            -0.049197438694357894_f64,
+            0.08883588610458339_f64,
+            -0.2145910886917563_f64,
+            1.570796238853147_f64,
+        ],
+        4 => &[
+            -0.0004741807779715293_f64,
+            0.0028157369405024464_f64,
+            -0.00796996734439965_f64,
+            0.015072939700871224_f64,
+            -0.023117940332331683_f64,
+            0.033402785256290916_f64,
+            -0.05075657350102964_f64,
+            0.08904620437045221_f64,
+            -0.21460177392857124_f64,
+            1.570796326528292_f64,
+        ],
+        5 => &[
+            -0.00016722162920434697_f64,
+            0.0011512261722573427_f64,
+            -0.003747722478126744_f64,
+            0.00791671178816037_f64,
+            -0.012794343380556239_f64,
+            0.017952218538231406_f64,
+            -0.02420551184850207_f64,
+            0.033655701034597925_f64,
+            -0.05079050225293784_f64,
+            0.0890485141037462_f64,
+            -0.21460183461099339_f64,
+            1.570796326788868_f64,
+        ],
+        6 => &[
+            3.807585815442301e-05_f64,
+            -0.00031733980980765526_f64,
+            0.0012492495495628933_f64,
+            -0.003140378550193775_f64,
+            0.005806984215735517_f64,
+            -0.008729731259854625_f64,
+            0.011620233700892818_f64,
+            -0.014711018070076284_f64,
+            0.018635307227248182_f64,
+            -0.02436891408548721_f64,
+            0.033680766040200746_f64,
+            -0.050792784272142295_f64,
+            0.08904862162613697_f64,
+            -0.21460183659147888_f64,
+            1.5707963267948746_f64,
+        ],
+        7 => &[
+            -9.266645098066065e-06_f64,
+            9.083995733594514e-05_f64,
+            -0.00042183840858969475_f64,
+            0.0012463265805250957_f64,
+            -0.002669231511728182_f64,
+            0.004509516483017543_f64,
+            -0.006440057523398855_f64,
+            0.00827568968016562_f64,
+            -0.010099914699793867_f64,
+            0.012182023372715805_f64,
+            -0.014879123977304752_f64,
+            0.01867123888823975_f64,
+            -0.02437421737031378_f64,
+            0.033681279022689796_f64,
+            -0.0507928142087059_f64,
+            0.08904862254093004_f64,
+            -0.21460183660249108_f64,
+            1.5707963267948966_f64,
+        ],
+        _ => unreachable!(),
+    }
+}
+
+/// Coefficients for `log2(1 + x)` between [0, 1). Unlike the `f32` polynomial, this takes
+/// `mantissa - 1` instead of the mantissa, because the coefficients for the higher degrees get very
+/// large and lose precision otherwise.
+#[inline(always)]
+#[allow(clippy::approx_constant)]
+pub(crate) fn log2_coeffs<P: Precision<f64>>() -> &'static [f64] {
+    match P::LEVEL {
+        0 => &[
+            -0.3448484329998199_f64,
+            1.3349689130472895_f64,
+            0.004939759976265141_f64,
+        ],
+        1 => &[
+            0.15824870260829935_f64,
+            -0.5771289138925605_f64,
+            1.4188802112842611_f64,
+            0.0006371172920958004_f64,
+        ],
+        2 => &[
+            -0.08161580849848131_f64,
+            0.3186791295960962_f64,
+            -0.6749428913404767_f64,
+            1.4377043863944798_f64,
+            8.759192419102157e-05_f64,
+        ],
+        3 => &[
+            0.04487361019470899_f64,
+            -0.19219563554817448_f64,
+            0.41363011950297646_f64,
+            -0.7079926511767315_f64,
+            1.4416845570272205_f64,
+            1.2538744947624516e-05_f64,
+        ],
+        4 => &[
+            0.0021396195855223734_f64,
+            -0.01511187792508578_f64,
+            0.05008252463278539_f64,
+            -0.10627710342743657_f64,
+            0.16901536013698648_f64,
+            -0.2271709435115869_f64,
+            0.28527640377687846_f64,
+            -0.36015233382444445_f64,
+            0.48084838124640605_f64,
+            -0.7213450224052601_f64,
+            1.4426949917152352_f64,
+            1.6048847392706748e-10_f64,
+        ],
+        5 => &[
+            0.0008532374255009519_f64,
+            -0.006858539028198591_f64,
+            0.025906148641920076_f64,
+            -0.06218074716390102_f64,
+            0.10937117144233681_f64,
+            -0.15614285097131847_f64,
+            0.19755865783465573_f64,
+            -0.23827606536307339_f64,
+            0.2881553734603984_f64,
+            -0.36062924289267667_f64,
+            0.48089522273365465_f64,
+            -0.7213474053320287_f64,
+            1.4426950392127302_f64,
+            4.05171486254427e-12_f64,
+        ],
+        6 => &[
+            -0.00022423750456674701_f64,
+            0.0021333011467799387_f64,
+            -0.009578263331020541_f64,
+            0.027258588612016008_f64,
+            -0.05603977653962906_f64,
+            0.09064838429541693_f64,
+            -0.12380319774864032_f64,
+            0.15219477951179564_f64,
+            -0.1778710194928708_f64,
+            0.2055356185512195_f64,
+            -0.2403550784527521_f64,
+            0.28852797400472524_f64,
+            -0.36067290063674534_f64,
+            0.4808983061252935_f64,
+            -0.7213475194198221_f64,
+            1.4426950408787664_f64,
+            1.6862864647543775e-14_f64,
+        ],
+        7 => &[
+            6.106728702756447e-05_f64,
+            -0.0006715626916559947_f64,
+            0.0035019699956618058_f64,
+            -0.011591765503243262_f64,
+            0.02759878242816183_f64,
+            -0.05101902261978042_f64,
+            0.07766179282448667_f64,
+            -0.10259951139398402_f64,
+            0.1235731360336766_f64,
+            -0.1416560507795265_f64,
+            0.1595879971410161_f64,
+            -0.18018659232586123_f64,
+            0.20607519521182657_f64,
+            -0.2404463254702695_f64,
+            0.2885387697814079_f64,
+            -0.36067374687670994_f64,
+            0.48089834650501356_f64,
+            -0.7213475204361509_f64,
+            1.4426950408889032_f64,
+            7.24261058749412e-17_f64,
+        ],
+        _ => unreachable!(),
+    }
+}
+
+/// Coefficients for `2^x` between [-0.5, 0.5].
+#[inline(always)]
+#[allow(clippy::approx_constant)]
+pub(crate) fn exp2_coeffs<P: Precision<f64>>() -> &'static [f64] {
+    match P::LEVEL {
+        0 => &[
+            0.2384289357640615_f64,
+            0.7034480058912924_f64,
+            1.0004431419562634_f64,
+        ],
+        1 => &[
+            0.055171669058039205_f64,
+            0.24261112219321987_f64,
+            0.6932609854606234_f64,
+            0.9999280735393948_f64,
+        ],
+        2 => &[
+            0.009570101911206923_f64,
+            0.05591786031938863_f64,
+            0.24024744827862585_f64,
+            0.6931218147366882_f64,
+            0.9999992614457124_f64,
+        ],
+        3 => &[
+            0.0013276471992238455_f64,
+            0.009675541334444912_f64,
+            0.05550713273498869_f64,
+            0.24022119723840193_f64,
+            0.6931469670647601_f64,
+            1.0000000716546849_f64,
+        ],
+        4 => &[
+            1.5201922643867329e-05_f64,
+            0.00015469291129672786_f64,
+            0.0013333922559115897_f64,
+            0.009618027253714257_f64,
+            0.05550410353453057_f64,
+            0.24022651198157205_f64,
+            0.6931471807284456_f64,
+            0.999999999961682_f64,
+        ],
+        5 => &[
+            1.3175858190233019e-06_f64,
+            1.5309737421286156e-05_f64,
+            0.0001540385174836813_f64,
+            0.001333345206225163_f64,
+            0.009618128542862907_f64,
+            0.05550410939341707_f64,
+            0.24022650698880368_f64,
+            0.6931471805465141_f64,
+            0.9999999999997623_f64,
+        ],
+        6 => &[
+            1.01503367052523e-07_f64,
+            1.3259405609345196e-06_f64,
+            1.5252984838653776e-05_f64,
+            0.0001540343494807179_f64,
+            0.0013333557617604443_f64,
+            0.009618129192067245_f64,
+            0.05550410866868561_f64,
+            0.24022650695649653_f64,
+            0.693147180559871_f64,
+            1.0000000000000127_f64,
+        ],
+        7 => &[
+            4.435281044155608e-10_f64,
+            7.0741056276807116e-09_f64,
+            1.0178198032658642e-07_f64,
+            1.321543308958549e-06_f64,
+            1.5252733489962431e-05_f64,
+            0.00015403530462514633_f64,
+            0.0013333558146789949_f64,
+            0.009618129107588335_f64,
+            0.055504108664819925_f64,
+            0.24022650695910155_f64,
+            0.6931471805599453_f64,
+            1.0_f64,
+        ],
+        _ => unreachable!(),
+    }
+}
+
+/// Coefficients for `sinh(x) / x` between [-1, 1], in terms of `x^2`.
+#[inline(always)]
+pub(crate) fn sinh_coeffs<P: Precision<f64>>() -> &'static [f64] {
+    match P::LEVEL {
+        0 => &[0.175026921756957_f64, 0.9990053042264155_f64],
+        1 => &[
+            0.008631882915055388_f64,
+            0.16655653099256792_f64,
+            1.000005875197299_f64,
+        ],
+        2 => &[
+            0.00020393989736013782_f64,
+            0.008329911920279771_f64,
+            0.16666733826970648_f64,
+            0.9999999797007373_f64,
+        ],
+        3 | 4 => &[
+            2.8185239429898033e-6_f64,
+            0.00019835812447427771_f64,
+            0.008333352592908724_f64,
+            0.16666666430246774_f64,
+            1.0000000000459763_f64,
+        ],
+        5 | 6 => &[
+            2.5535007680790974e-8_f64,
+            2.755191453290298e-6_f64,
+            0.00019841297622835924_f64,
+            0.008333333269011865_f64,
+            0.16666666667208738_f64,
+            0.9999999999999265_f64,
+        ],
+        7 => &[
+            1.6327265681011653e-10_f64,
+            2.5048435629675862e-8_f64,
+            2.755734409517448e-6_f64,
+            0.00019841269755015088_f64,
+            0.008333333333475386_f64,
+            0.16666666666665791_f64,
+            1.0_f64,
+        ],
+        _ => unreachable!(),
+    }
+}
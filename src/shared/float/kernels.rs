@@ -0,0 +1,628 @@
+/// The bits of 1/PI, starting from the integer part. Word `i` holds `floor(2^(32 * i) / PI)` mod
+/// 2^32. This covers every window that the Payne-Hanek reduction needs for finite `f64` inputs.
+pub(crate) const FRAC_1_PI_BITS: [u32; 36] = [
+    0x0000_0000,
+    0x517c_c1b7,
+    0x2722_0a94,
+    0xfe13_abe8,
+    0xfa9a_6ee0,
+    0x6db1_4acc,
+    0x9e21_c820,
+    0xff28_b1d5,
+    0xef5d_e2b0,
+    0xdb92_371d,
+    0x2126_e970,
+    0x0324_9775,
+    0x04e8_c90e,
+    0x7f0e_f58e,
+    0x5894_d39f,
+    0x7441_1afa,
+    0x975d_a242,
+    0x74ce_3813,
+    0x5a2f_bf20,
+    0x9cc8_eb1c,
+    0xc1a9_9cfa,
+    0x4e42_2fc5,
+    0xdefc_941d,
+    0x8ffc_4bff,
+    0xef02_cc07,
+    0xf797_88c5,
+    0xad05_368f,
+    0xb69b_3f67,
+    0x93e5_84db,
+    0xa7a3_1fb3,
+    0x4f2f_f516,
+    0xba93_dd63,
+    0xf5f2_f8bd,
+    0x9e83_9cfb,
+    0xc529_4975,
+    0x35fd_afd8,
+];
+
+/// Generates the scalar kernels for a float type. The module that this is invoked in has to
+/// provide the coefficient functions and the magic numbers for the type, because the coefficients
+/// and the amount of precision levels differ between types.
+///
+/// Parameters are the float type, the unsigned and signed int types of the same size, the unsigned
+/// and signed int types of twice the size, the rounding and square root intrinsics, the amount of
+/// explicit mantissa bits, and the value that the log2 polynomial's input is offset by.
+macro_rules! float_kernels {
+    (
+        $f:ident,$u:ty,$s:ty,$w:ty,$sw:ty,$nearbyint:ident,$sqrt:ident,$mant_bits:expr,
+        $log2_origin:expr
+    ) => {
+        use crate::shared::float::kernels::FRAC_1_PI_BITS;
+        use crate::shared::float::Precision;
+        use crate::shared::intrinsics::*;
+        use core::$f::consts::*;
+
+        const SIGN_MASK: $u = 1 << (<$u>::BITS - 1);
+        const EXP_BIAS: $s = (1 << (<$u>::BITS - 2 - $mant_bits)) - 1;
+        // the smallest multiple of 6 above the amount of mantissa bits. scaling a subnormal by 2
+        // to the power of this makes it normal, and the square and cube roots of the scale are
+        // still powers of 2.
+        const SUBNORMAL_SHIFT: $s = ($mant_bits + 6) / 6 * 6;
+
+        /// Calculates `2^exponent` for exponents in the normal range.
+        const fn exp2_int(exponent: $s) -> $f {
+            $f::from_bits(((EXP_BIAS + exponent) as $u) << $mant_bits)
+        }
+
+        /// The largest magnitude that the checked `sin`, `cos` and `tan` functions use the fast
+        /// range reduction for. Its error grows to about `|x| * EPSILON`, so past this it would
+        /// be larger than the error of the polynomial.
+        #[inline(always)]
+        pub(crate) fn checked_trig_max<P: Precision<$f>>() -> $f {
+            P::SIN_MAX_ERROR / $f::EPSILON
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn sin_fast_approx<P: Precision<$f>, const COS: bool>(x: $f) -> $f {
+            let coeffs = cos_pi_fraction_coeffs::<P>();
+
+            // the range reduction uses plain arithmetic, because the fast intrinsics could be
+            // contracted into a fused multiply-add, which rounds differently from the SIMD impls
+            let pi_multiples = x * FRAC_1_PI + if COS { 0.0 } else { -0.5 };
+            let rounded_multiples = $nearbyint(pi_multiples);
+            let pi_fraction = pi_multiples - rounded_multiples;
+            let fraction_squared = pi_fraction * pi_fraction;
+
+            let mut polynomial_eval = coeffs[0];
+            for &coeff in &coeffs[1..] {
+                polynomial_eval = fadd_fast(fmul_fast(fraction_squared, polynomial_eval), coeff);
+            }
+
+            let parity_sign =
+                (rounded_multiples.to_int_unchecked::<$s>() as $u) << (<$u>::BITS - 1);
+            $f::from_bits(polynomial_eval.to_bits() ^ parity_sign)
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn sincos_fast_approx<P: Precision<$f>>(x: $f) -> ($f, $f) {
+            let sin_coeffs = sin_pi_fraction_coeffs::<P>();
+            let cos_coeffs = cos_pi_fraction_coeffs::<P>();
+
+            let pi_multiples = x * FRAC_1_PI;
+            let rounded_multiples = $nearbyint(pi_multiples);
+            let pi_fraction = pi_multiples - rounded_multiples;
+            let fraction_squared = pi_fraction * pi_fraction;
+
+            let mut sin_polynomial_eval = sin_coeffs[0];
+            for &coeff in &sin_coeffs[1..] {
+                sin_polynomial_eval =
+                    fadd_fast(fmul_fast(fraction_squared, sin_polynomial_eval), coeff);
+            }
+            sin_polynomial_eval *= pi_fraction;
+
+            let mut cos_polynomial_eval = cos_coeffs[0];
+            for &coeff in &cos_coeffs[1..] {
+                cos_polynomial_eval =
+                    fadd_fast(fmul_fast(fraction_squared, cos_polynomial_eval), coeff);
+            }
+
+            // both sin and cos flip signs every PI
+            let parity_sign =
+                (rounded_multiples.to_int_unchecked::<$s>() as $u) << (<$u>::BITS - 1);
+            (
+                $f::from_bits(sin_polynomial_eval.to_bits() ^ parity_sign),
+                $f::from_bits(cos_polynomial_eval.to_bits() ^ parity_sign),
+            )
+        }
+
+        pub(crate) unsafe fn cos_restrict_fast_approx<P: Precision<$f>>(x: $f) -> $f {
+            let coeffs = cos_restrict_coeffs::<P>();
+
+            let x_squared = x * x;
+
+            let mut polynomial_eval = coeffs[0];
+            for &coeff in &coeffs[1..] {
+                polynomial_eval = fadd_fast(fmul_fast(x_squared, polynomial_eval), coeff);
+            }
+
+            polynomial_eval
+        }
+
+        pub(crate) unsafe fn sin_restrict_fast_approx<P: Precision<$f>>(x: $f) -> $f {
+            let coeffs = sin_restrict_coeffs::<P>();
+
+            let x_squared = x * x;
+
+            let mut polynomial_eval = coeffs[0];
+            for &coeff in &coeffs[1..] {
+                polynomial_eval = fadd_fast(fmul_fast(x_squared, polynomial_eval), coeff);
+            }
+            polynomial_eval *= x;
+
+            polynomial_eval
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn sin_wide_fast_approx<P: Precision<$f>, const COS: bool>(x: $f) -> $f {
+            let (remainder, parity_sign) = reduce_pi_multiples(x);
+
+            let polynomial_eval = if COS {
+                cos_restrict_fast_approx::<P>(remainder)
+            } else {
+                sin_restrict_fast_approx::<P>(remainder)
+            };
+
+            $f::from_bits(polynomial_eval.to_bits() ^ parity_sign)
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn sincos_wide_fast_approx<P: Precision<$f>>(x: $f) -> ($f, $f) {
+            let (remainder, parity_sign) = reduce_pi_multiples(x);
+
+            (
+                $f::from_bits(sin_restrict_fast_approx::<P>(remainder).to_bits() ^ parity_sign),
+                $f::from_bits(cos_restrict_fast_approx::<P>(remainder).to_bits() ^ parity_sign),
+            )
+        }
+
+        /// Reduces `x` to a remainder between [-PI/2, PI/2] by subtracting the nearest multiple of
+        /// PI. Also returns the sign bit to flip the result by, which is set when the multiple is
+        /// odd.
+        #[inline(always)]
+        unsafe fn reduce_pi_multiples(x: $f) -> ($f, $u) {
+            if $f::from_bits(x.to_bits() & !SIGN_MASK) <= CODY_WAITE_MAX {
+                reduce_pi_multiples_cody_waite(x)
+            } else {
+                reduce_pi_multiples_payne_hanek(x)
+            }
+        }
+
+        /// Subtracts the multiple of PI in parts, so the result doesn't lose the bits that a
+        /// single multiply would round off. Only valid for inputs up to `CODY_WAITE_MAX`.
+        #[inline(always)]
+        pub(crate) unsafe fn reduce_pi_multiples_cody_waite(x: $f) -> ($f, $u) {
+            let rounded_multiples = $nearbyint(x * FRAC_1_PI);
+
+            // the fast intrinsics can't be used here, because the subtractions have to happen in
+            // this order
+            let remainder = ((x - rounded_multiples * PI_SPLIT[0])
+                - rounded_multiples * PI_SPLIT[1])
+                - rounded_multiples * PI_SPLIT[2];
+
+            let parity_sign =
+                (rounded_multiples.to_int_unchecked::<$s>() as $u) << (<$u>::BITS - 1);
+            (remainder, parity_sign)
+        }
+
+        /// Multiplies the integer mantissa of `x` by a window of the bits of 1/PI, which gives
+        /// `x / PI` in fixed point. Bits of 1/PI above the window only add even multiples of PI,
+        /// and the bits below it are too small to matter, so this is accurate for any finite
+        /// input. Only valid for inputs above `CODY_WAITE_MAX`.
+        #[inline(always)]
+        pub(crate) fn reduce_pi_multiples_payne_hanek(x: $f) -> ($f, $u) {
+            const MANTISSA_MASK: $u = (1 << $mant_bits) - 1;
+            const WINDOW_WORDS: usize = (<$w>::BITS / u32::BITS) as usize;
+
+            let bits = x.to_bits();
+            // x = mantissa * 2^exponent
+            let mantissa = ((bits & MANTISSA_MASK) | (MANTISSA_MASK + 1)) as $w;
+            let exponent = ((bits & !SIGN_MASK) >> $mant_bits) as $s - EXP_BIAS - $mant_bits;
+
+            // the window is floor(2^(exponent + wide bits - 1) / PI) mod 2^(wide bits), so the
+            // product has 1 integer bit, and the rest are the fraction
+            let window_start = (exponent + <$w>::BITS as $s - 1) as usize;
+            let word_index = window_start / u32::BITS as usize;
+            let word_shift = window_start % u32::BITS as usize;
+
+            let mut window: $w = 0;
+            for &word in &FRAC_1_PI_BITS[word_index + 1 - WINDOW_WORDS..=word_index] {
+                window = (window << u32::BITS) | word as $w;
+            }
+            let next_word = FRAC_1_PI_BITS[word_index + 1] as u64;
+            window = (window << word_shift) | ((next_word << word_shift) >> u32::BITS) as $w;
+
+            let fixed = mantissa.wrapping_mul(window);
+
+            // rounding to the nearest multiple flips the parity when the fraction is at least 0.5,
+            // and reinterpreting the fraction as signed does the same rounding
+            let parity = (fixed ^ (fixed << 1)) >> (<$w>::BITS - 1);
+            let fraction = (fixed << 1) as $sw;
+
+            // the fraction is multiplied by PI in fixed point, after normalizing it so that small
+            // fractions keep their precision. this only rounds once, when converting to a float.
+            // the lowest bit is set to keep the shift in range, and is too small to matter
+            let magnitude = fraction.unsigned_abs() | 1;
+            let shift = magnitude.leading_zeros();
+            let normalized = ((magnitude << shift) >> <$u>::BITS) as $w;
+            let product = normalized * PI_FIXED as $w;
+            // 2^(2 - shift - wide bits), which undoes the normalization and the fixed point scales
+            let scale = $f::from_bits(
+                ((EXP_BIAS + 2 - shift as $s - <$w>::BITS as $s) as $u) << $mant_bits,
+            );
+            let remainder = product as $f * scale;
+
+            // the mantissa doesn't include the sign of x, so it's applied here too
+            let sign = ((fraction >> (<$w>::BITS - 1)) as $u & SIGN_MASK) ^ (bits & SIGN_MASK);
+            (
+                $f::from_bits(remainder.to_bits() | sign),
+                (parity as $u) << (<$u>::BITS - 1),
+            )
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn tan_fast_approx<P: Precision<$f>>(x: $f) -> $f {
+            let half_pi_multiples = x * FRAC_2_PI;
+            let rounded_multiples = $nearbyint(half_pi_multiples);
+            let half_pi_fraction = half_pi_multiples - rounded_multiples;
+
+            let reciprocal = (rounded_multiples.to_int_unchecked::<$s>() & 1) != 0;
+
+            tan_half_pi_fraction::<P>(half_pi_fraction, reciprocal)
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn tan_restrict_fast_approx<P: Precision<$f>>(x: $f) -> $f {
+            let half_pi_multiples = x * FRAC_2_PI;
+            let sign = half_pi_multiples.to_bits() & SIGN_MASK;
+
+            // past ±PI/4, the input is shifted by PI/2 towards 0 to use the reciprocal identity
+            let abs_multiples = $f::from_bits(half_pi_multiples.to_bits() ^ sign);
+            let reciprocal = abs_multiples > 0.5;
+            let half_pi_fraction = if reciprocal {
+                // the sign is applied after the subtraction so that a fraction of exactly 0 still
+                // keeps the sign of the pole's side
+                $f::from_bits((1.0 - abs_multiples).to_bits() ^ sign ^ SIGN_MASK)
+            } else {
+                half_pi_multiples
+            };
+
+            tan_half_pi_fraction::<P>(half_pi_fraction, reciprocal)
+        }
+
+        /// Calculates `tan(fraction * PI/2)` for fractions between [-0.5, 0.5], or `-1/tan(...)`
+        /// for fractions that are offset from a pole when `reciprocal` is set.
+        #[inline(always)]
+        unsafe fn tan_half_pi_fraction<P: Precision<$f>>(fraction: $f, reciprocal: bool) -> $f {
+            // 2^-(mantissa bits + 1), which keeps the reciprocal at or below 2^(mantissa bits + 1)
+            const MIN_ABS_BITS: $u = ((EXP_BIAS - 1 - $mant_bits) as $u) << $mant_bits;
+
+            let coeffs = tan_half_pi_fraction_coeffs::<P>();
+
+            let fraction_squared = fraction * fraction;
+
+            let mut polynomial_eval = coeffs[0];
+            for &coeff in &coeffs[1..] {
+                polynomial_eval = fadd_fast(fmul_fast(fraction_squared, polynomial_eval), coeff);
+            }
+            polynomial_eval *= fraction;
+
+            if reciprocal {
+                // clamping the magnitude turns the poles into large finite values instead of
+                // infinity
+                let bits = polynomial_eval.to_bits();
+                let clamped =
+                    $f::from_bits((bits & !SIGN_MASK).max(MIN_ABS_BITS) | (bits & SIGN_MASK));
+                fdiv_fast(-1.0, clamped)
+            } else {
+                polynomial_eval
+            }
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn atan_fast_approx<P: Precision<$f>>(x: $f) -> $f {
+            let sign = x.to_bits() & SIGN_MASK;
+            let abs = $f::from_bits(x.to_bits() ^ sign);
+
+            // atan(x) = PI/2 - atan(1/x) for positive x
+            let reciprocal = abs > 1.0;
+            let reduced = if reciprocal { fdiv_fast(1.0, abs) } else { abs };
+
+            let mut result = atan_restrict::<P>(reduced);
+            if reciprocal {
+                result = FRAC_PI_2 - result;
+            }
+
+            $f::from_bits(result.to_bits() | sign)
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn atan2_fast_approx<P: Precision<$f>>(y: $f, x: $f) -> $f {
+            let y_sign = y.to_bits() & SIGN_MASK;
+            let x_sign = x.to_bits() & SIGN_MASK;
+            let y_abs = $f::from_bits(y.to_bits() ^ y_sign);
+            let x_abs = $f::from_bits(x.to_bits() ^ x_sign);
+
+            // keeps the ratio between [0, 1], which is then reflected back into the first octant
+            let swap = y_abs > x_abs;
+            let ratio = if swap {
+                fdiv_fast(x_abs, y_abs)
+            } else {
+                fdiv_fast(y_abs, x_abs)
+            };
+
+            let mut result = atan_restrict::<P>(ratio);
+            if swap {
+                result = FRAC_PI_2 - result;
+            }
+            if x_sign != 0 {
+                result = PI - result;
+            }
+
+            $f::from_bits(result.to_bits() | y_sign)
+        }
+
+        /// Calculates `atan(x)` for inputs between [0, 1].
+        #[inline(always)]
+        unsafe fn atan_restrict<P: Precision<$f>>(x: $f) -> $f {
+            let coeffs = atan_restrict_coeffs::<P>();
+
+            let x_squared = x * x;
+
+            let mut polynomial_eval = coeffs[0];
+            for &coeff in &coeffs[1..] {
+                polynomial_eval = fadd_fast(fmul_fast(x_squared, polynomial_eval), coeff);
+            }
+            polynomial_eval *= x;
+
+            polynomial_eval
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn asin_fast_approx<P: Precision<$f>>(x: $f) -> $f {
+            FRAC_PI_2 - acos_fast_approx::<P>(x)
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn acos_fast_approx<P: Precision<$f>>(x: $f) -> $f {
+            let coeffs = acos_coeffs::<P>();
+
+            let sign = x.to_bits() & SIGN_MASK;
+            let abs = $f::from_bits(x.to_bits() ^ sign);
+
+            // acos(x) = sqrt(1 - x) * P(x) for positive x
+            let mut polynomial_eval = coeffs[0];
+            for &coeff in &coeffs[1..] {
+                polynomial_eval = fadd_fast(fmul_fast(abs, polynomial_eval), coeff);
+            }
+            let result = fmul_fast(polynomial_eval, $sqrt(1.0 - abs));
+
+            // acos(-x) = PI - acos(x)
+            if sign != 0 {
+                PI - result
+            } else {
+                result
+            }
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn log_fast_approx<P: Precision<$f>>(x: $f, base: $f) -> $f {
+            fdiv_fast(log2_fast_approx::<P>(x), log2_fast_approx::<P>(base))
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn ln_fast_approx<P: Precision<$f>>(x: $f) -> $f {
+            log2_fast_approx::<P>(x) * LN_2
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn log10_fast_approx<P: Precision<$f>>(x: $f) -> $f {
+            log2_fast_approx::<P>(x) * LOG10_2
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn log2_fast_approx<P: Precision<$f>>(x: $f) -> $f {
+            // clears the top 2 bits, then sets the exponent to the bias, putting it in [1, 2)
+            const MANTISSA_MASK: $u = <$u>::MAX >> 2;
+            const ONE_BITS: $u = (EXP_BIAS as $u) << $mant_bits;
+
+            let coeffs = log2_coeffs::<P>();
+
+            let mantissa = $f::from_bits(x.to_bits() & MANTISSA_MASK | ONE_BITS) - $log2_origin;
+
+            let mut mant_log2 = coeffs[0];
+            for &coeff in &coeffs[1..] {
+                mant_log2 = fadd_fast(fmul_fast(mantissa, mant_log2), coeff);
+            }
+
+            let exponent = ((x.to_bits() >> $mant_bits) as $s - EXP_BIAS) as $f;
+
+            exponent + mant_log2
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn pow_fast_approx<P: Precision<$f>>(x: $f, exponent: $f) -> $f {
+            exp2_fast_approx::<P>(fmul_fast(log2_fast_approx::<P>(x), exponent))
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn powf_const<
+            P: Precision<$f>,
+            const NUMERATOR: i32,
+            const DENOMINATOR: u32,
+        >(
+            x: $f,
+        ) -> $f {
+            if DENOMINATOR == 0 {
+                panic!("invalid denominator: {:?}", DENOMINATOR);
+            } else if DENOMINATOR == 1 {
+                powi_const::<NUMERATOR>(x)
+            } else {
+                pow_fast_approx::<P>(x, NUMERATOR as $f / DENOMINATOR as $f)
+            }
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn powi_const<const EXPONENT: i32>(x: $f) -> $f {
+            let mut remaining = EXPONENT.unsigned_abs();
+            let mut square = x;
+            let mut result = 1.0;
+
+            while remaining != 0 {
+                if remaining & 1 == 1 {
+                    result = fmul_fast(result, square);
+                }
+                remaining >>= 1;
+                if remaining != 0 {
+                    square = fmul_fast(square, square);
+                }
+            }
+
+            if EXPONENT < 0 {
+                fdiv_fast(1.0, result)
+            } else {
+                result
+            }
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn exp_fast_approx<P: Precision<$f>>(x: $f) -> $f {
+            exp2_fast_approx::<P>(x * LOG2_E)
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn exp10_fast_approx<P: Precision<$f>>(x: $f) -> $f {
+            exp2_fast_approx::<P>(x * LOG2_10)
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn exp2_fast_approx<P: Precision<$f>>(x: $f) -> $f {
+            let coeffs = exp2_coeffs::<P>();
+
+            // splitting at the nearest integer keeps the fraction within [-0.5, 0.5]
+            let rounded = $nearbyint(x);
+            let fraction = x - rounded;
+
+            let mut fract_exp2 = coeffs[0];
+            for &coeff in &coeffs[1..] {
+                fract_exp2 = fadd_fast(fmul_fast(fraction, fract_exp2), coeff);
+            }
+
+            // the integer part is added directly to the exponent bits of the fraction's result
+            let exponent = (rounded.to_int_unchecked::<$s>() << $mant_bits) as $u;
+
+            $f::from_bits(fract_exp2.to_bits().wrapping_add(exponent))
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn sinh_fast_approx<P: Precision<$f>>(x: $f) -> $f {
+            // near 0, e^x and e^-x cancel out most of the value, so a polynomial is used there
+            // instead
+            if $f::from_bits(x.to_bits() & !SIGN_MASK) < 1.0 {
+                let coeffs = sinh_coeffs::<P>();
+
+                let x_squared = fmul_fast(x, x);
+
+                let mut polynomial_eval = coeffs[0];
+                for &coeff in &coeffs[1..] {
+                    polynomial_eval = fadd_fast(fmul_fast(x_squared, polynomial_eval), coeff);
+                }
+
+                fmul_fast(polynomial_eval, x)
+            } else {
+                let exp = exp_fast_approx::<P>(x);
+                fmul_fast(fsub_fast(exp, fdiv_fast(1.0, exp)), 0.5)
+            }
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn cosh_fast_approx<P: Precision<$f>>(x: $f) -> $f {
+            let exp = exp_fast_approx::<P>(x);
+            fmul_fast(fadd_fast(exp, fdiv_fast(1.0, exp)), 0.5)
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn tanh_fast_approx<P: Precision<$f>>(x: $f) -> $f {
+            // tanh rounds to ±1 long before the input reaches the amount of mantissa bits, and
+            // clamping there keeps exp within its valid range
+            let clamped = x.max(-($mant_bits as $f)).min($mant_bits as $f);
+
+            // tanh(x) = 1 - 2 / (e^2x + 1)
+            let exp = exp2_fast_approx::<P>(fmul_fast(clamped, 2.0 * LOG2_E));
+            fsub_fast(1.0, fdiv_fast(2.0, fadd_fast(exp, 1.0)))
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn sqrt_fast_approx<P: Precision<$f>>(x: $f) -> $f {
+            // the reciprocal of 0 would be infinite, so the input to it is clamped to the
+            // smallest subnormal, and 0 is still multiplied back to 0
+            let clamped = if x > 0.0 { x } else { $f::from_bits(1) };
+            fmul_fast(x, rsqrt_fast_approx::<P>(clamped))
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn rsqrt_fast_approx<P: Precision<$f>>(x: $f) -> $f {
+            // the initial guess only works for normal inputs, so subnormals are scaled up first,
+            // and the result is scaled by the square root of the same factor
+            let subnormal = x < $f::MIN_POSITIVE;
+            let scaled = if subnormal {
+                fmul_fast(x, exp2_int(SUBNORMAL_SHIFT))
+            } else {
+                x
+            };
+
+            // shifting the bits right halves the exponent, and subtracting from the magic number
+            // negates it and fixes up the bias
+            let mut result = $f::from_bits(RSQRT_MAGIC - (scaled.to_bits() >> 1));
+
+            let half_x = fmul_fast(scaled, 0.5);
+            for _ in 0..P::LEVEL {
+                result = fmul_fast(
+                    result,
+                    fsub_fast(1.5, fmul_fast(half_x, fmul_fast(result, result))),
+                );
+            }
+
+            if subnormal {
+                fmul_fast(result, exp2_int(SUBNORMAL_SHIFT / 2))
+            } else {
+                result
+            }
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn cbrt_fast_approx<P: Precision<$f>>(x: $f) -> $f {
+            // the initial guess only works for normal inputs, so subnormals are scaled up first,
+            // and the reciprocal is scaled by the cube root of the same factor. 0 is clamped to the
+            // scaled smallest subnormal, because its reciprocal would be infinite.
+            let abs_bits = x.to_bits() & !SIGN_MASK;
+            let subnormal = abs_bits < $f::MIN_POSITIVE.to_bits();
+            let scaled = if subnormal {
+                fmul_fast($f::from_bits(abs_bits.max(1)), exp2_int(SUBNORMAL_SHIFT))
+            } else {
+                $f::from_bits(abs_bits)
+            };
+
+            // the reciprocal cube root is used because its newton iteration doesn't need a
+            // division, and multiplying it back keeps the sign and gives exactly 0 for 0
+            let mut rcbrt = $f::from_bits(RCBRT_MAGIC - scaled.to_bits() / 3);
+
+            let third_x = fmul_fast(scaled, 1.0 / 3.0);
+            for _ in 0..P::LEVEL {
+                let rcbrt_cubed = fmul_fast(fmul_fast(rcbrt, rcbrt), rcbrt);
+                rcbrt = fmul_fast(rcbrt, fsub_fast(4.0 / 3.0, fmul_fast(third_x, rcbrt_cubed)));
+            }
+
+            if subnormal {
+                rcbrt = fmul_fast(rcbrt, exp2_int(SUBNORMAL_SHIFT / 3));
+            }
+
+            fmul_fast(fmul_fast(x, rcbrt), rcbrt)
+        }
+    };
+}
+
+pub(crate) use float_kernels;
@@ -0,0 +1,684 @@
+/// Defines fast approximate functions for 32-bit and 64-bit floats. Outputs may differ based on
+/// platform, so nothing should be checked for equality. This is part of the reason why functions
+/// in here are marked as unsafe, because the behavior of these small floating point differences is
+/// undefined.
+///
+///
+/// Coefficient constants for the `sin` and `cos` functions were derived from here:
+/// https://publik-void.github.io/sin-cos-approximations/#_cos_abs_error_minimized_degree_2
+///
+///
+/// Other coefficients were generated from this Julia function:
+/// https://gist.github.com/burgerindividual/5f0ee20232f78c356df5767713ffad57
+pub trait FastApproxFloat: Sized {
+    /// The scalar float type, which the precision levels are defined for.
+    type Scalar;
+
+    /// # Inputs
+    /// Precision can set between [`P0`] and [`P3`] for `f32` types and between [`P0`] and [`P7`]
+    /// for `f64` types, with `P0` being the fastest and least precise, and the maximum being the
+    /// slowest and most precise.
+    ///
+    /// # Safety
+    /// Inputs valid between [-2^23, 2^23] for `f32` types and [-2^52, 2^52] for `f64` types. The
+    /// output of this function can differ based on machine characteristics, and should not be
+    /// used with equality testing.
+    ///
+    /// # Notes
+    /// As the inputs get further from 0, the accuracy gets continuously worse
+    /// due to nature of the fast range reduction.
+    unsafe fn sin_fast_approx<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Precision can set between [`P0`] and [`P3`] for `f32` types and between [`P0`] and [`P7`]
+    /// for `f64` types, with `P0` being the fastest and least precise, and the maximum being the
+    /// slowest and most precise.
+    ///
+    /// # Safety
+    /// Inputs valid between [-2^23, 2^23] for `f32` types and [-2^52, 2^52] for `f64` types. The
+    /// output of this function can differ based on machine characteristics, and should not be
+    /// used with equality testing.
+    ///
+    /// # Notes
+    /// As the inputs get further from 0, the accuracy gets continuously worse
+    /// due to nature of the fast range reduction.
+    unsafe fn cos_fast_approx<P: Precision<Self::Scalar>>(self) -> Self;
+    /// Calculates both `sin` and `cos`, returned in that order. This is faster than calling
+    /// both functions separately, because the range reduction is only done once.
+    ///
+    /// # Inputs
+    /// Precision can set between [`P0`] and [`P3`] for `f32` types and between [`P0`] and [`P7`]
+    /// for `f64` types, with `P0` being the fastest and least precise, and the maximum being the
+    /// slowest and most precise.
+    ///
+    /// # Safety
+    /// Inputs valid between [-2^23, 2^23] for `f32` types and [-2^52, 2^52] for `f64` types. The
+    /// output of this function can differ based on machine characteristics, and should not be
+    /// used with equality testing.
+    ///
+    /// # Notes
+    /// As the inputs get further from 0, the accuracy gets continuously worse
+    /// due to nature of the fast range reduction.
+    ///
+    /// The `sin` output uses a separate polynomial from `sin_fast_approx`, so the two won't
+    /// return the exact same values.
+    unsafe fn sincos_fast_approx<P: Precision<Self::Scalar>>(self) -> (Self, Self);
+
+    /// # Inputs
+    /// Precision can set between [`P0`] and [`P3`] for `f32` types and between [`P0`] and [`P7`]
+    /// for `f64` types, with `P0` being the fastest and least precise, and the maximum being the
+    /// slowest and most precise.
+    ///
+    /// # Safety
+    /// Inputs valid for all finite values. The output of this function can differ based on
+    /// machine characteristics, and should not be used with equality testing.
+    ///
+    /// # Notes
+    /// Unlike `sin_fast_approx`, this uses an accurate range reduction, so the accuracy stays the
+    /// same for large inputs. Inputs up to 2^13 for `f32` types and 2^27 for `f64` types use a
+    /// Cody-Waite reduction, and larger inputs use a slower Payne-Hanek reduction. For SIMD types,
+    /// the Payne-Hanek reduction is done per-lane.
+    unsafe fn sin_wide_fast_approx<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Precision can set between [`P0`] and [`P3`] for `f32` types and between [`P0`] and [`P7`]
+    /// for `f64` types, with `P0` being the fastest and least precise, and the maximum being the
+    /// slowest and most precise.
+    ///
+    /// # Safety
+    /// Inputs valid for all finite values. The output of this function can differ based on
+    /// machine characteristics, and should not be used with equality testing.
+    ///
+    /// # Notes
+    /// Unlike `cos_fast_approx`, this uses an accurate range reduction, so the accuracy stays the
+    /// same for large inputs. Inputs up to 2^13 for `f32` types and 2^27 for `f64` types use a
+    /// Cody-Waite reduction, and larger inputs use a slower Payne-Hanek reduction. For SIMD types,
+    /// the Payne-Hanek reduction is done per-lane.
+    unsafe fn cos_wide_fast_approx<P: Precision<Self::Scalar>>(self) -> Self;
+    /// Calculates both `sin` and `cos`, returned in that order, with the range reduction from
+    /// `sin_wide_fast_approx` done only once.
+    ///
+    /// # Inputs
+    /// Precision can set between [`P0`] and [`P3`] for `f32` types and between [`P0`] and [`P7`]
+    /// for `f64` types, with `P0` being the fastest and least precise, and the maximum being the
+    /// slowest and most precise.
+    ///
+    /// # Safety
+    /// Inputs valid for all finite values. The output of this function can differ based on
+    /// machine characteristics, and should not be used with equality testing.
+    ///
+    /// # Notes
+    /// Unlike `sincos_fast_approx`, this uses an accurate range reduction, so the accuracy stays
+    /// the same for large inputs. Inputs up to 2^13 for `f32` types and 2^27 for `f64` types use a
+    /// Cody-Waite reduction, and larger inputs use a slower Payne-Hanek reduction. For SIMD types,
+    /// the Payne-Hanek reduction is done per-lane.
+    unsafe fn sincos_wide_fast_approx<P: Precision<Self::Scalar>>(self) -> (Self, Self);
+
+    /// # Inputs
+    /// Precision can set between [`P0`] and [`P3`] for `f32` types and between [`P0`] and [`P7`]
+    /// for `f64` types, with `P0` being the fastest and least precise, and the maximum being the
+    /// slowest and most precise.
+    ///
+    /// # Safety
+    /// Inputs valid between [-PI/2, PI/2]. The output of this function can differ based on
+    /// machine characteristics, and should not be used with equality testing.
+    unsafe fn sin_restrict_fast_approx<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Precision can set between [`P0`] and [`P3`] for `f32` types and between [`P0`] and [`P7`]
+    /// for `f64` types, with `P0` being the fastest and least precise, and the maximum being the
+    /// slowest and most precise.
+    ///
+    /// # Safety
+    /// Inputs valid between [-PI/2, PI/2]. The output of this function can differ based on
+    /// machine characteristics, and should not be used with equality testing.
+    unsafe fn cos_restrict_fast_approx<P: Precision<Self::Scalar>>(self) -> Self;
+
+    /// # Inputs
+    /// Precision can set between [`P0`] and [`P3`] for `f32` types and between [`P0`] and [`P7`]
+    /// for `f64` types, with `P0` being the fastest and least precise, and the maximum being the
+    /// slowest and most precise.
+    ///
+    /// # Safety
+    /// Inputs valid between [-2^23, 2^23] for `f32` types and [-2^52, 2^52] for `f64` types. The
+    /// output of this function can differ based on machine characteristics, and should not be
+    /// used with equality testing.
+    ///
+    /// # Notes
+    /// As the inputs get further from 0, the accuracy gets continuously worse
+    /// due to nature of the fast range reduction.
+    ///
+    /// Near the poles at ±PI/2 (repeating every PI), the output saturates at a magnitude of 2^24
+    /// for `f32` types and 2^53 for `f64` types, instead of reaching infinity. The sign of the
+    /// output matches the side of the pole that the input was on, but inputs that land exactly on
+    /// a pole after the range reduction output the negative value.
+    unsafe fn tan_fast_approx<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Precision can set between [`P0`] and [`P3`] for `f32` types and between [`P0`] and [`P7`]
+    /// for `f64` types, with `P0` being the fastest and least precise, and the maximum being the
+    /// slowest and most precise.
+    ///
+    /// # Safety
+    /// Inputs valid between [-PI/2, PI/2]. The output of this function can differ based on
+    /// machine characteristics, and should not be used with equality testing.
+    ///
+    /// # Notes
+    /// Near the poles at ±PI/2, the output saturates at a magnitude of 2^24 for `f32` types and
+    /// 2^53 for `f64` types instead of reaching infinity, with the same sign as the input.
+    unsafe fn tan_restrict_fast_approx<P: Precision<Self::Scalar>>(self) -> Self;
+
+    /// # Inputs
+    /// Precision can set between [`P0`] and [`P3`] for `f32` types and between [`P0`] and [`P7`]
+    /// for `f64` types, with `P0` being the fastest and least precise, and the maximum being the
+    /// slowest and most precise.
+    ///
+    /// # Safety
+    /// Inputs valid between (-Infinity, Infinity). The output of this function can differ based on
+    /// machine characteristics, and should not be used with equality testing.
+    ///
+    /// # Notes
+    /// For `f32` types, the maximum absolute error for each precision level is about 8.2e-5,
+    /// 1.2e-5, 2.0e-6 and 5.2e-7. For `f64` types, it's about 8.1e-5, 1.1e-5, 1.7e-6, 2.5e-7,
+    /// 8.9e-10, 3.5e-12, 9.1e-14 and 4.4e-16.
+    unsafe fn atan_fast_approx<P: Precision<Self::Scalar>>(self) -> Self;
+    /// Calculates the four-quadrant arctangent of `self` (the y coordinate) and `x`.
+    ///
+    /// # Inputs
+    /// Precision can set between [`P0`] and [`P3`] for `f32` types and between [`P0`] and [`P7`]
+    /// for `f64` types, with `P0` being the fastest and least precise, and the maximum being the
+    /// slowest and most precise.
+    ///
+    /// # Safety
+    /// Inputs valid between (-Infinity, Infinity), where `self` and `x` are not both 0. The output
+    /// of this function can differ based on machine characteristics, and should not be used with
+    /// equality testing.
+    ///
+    /// # Notes
+    /// For `f32` types, the maximum absolute error for each precision level is about 8.2e-5,
+    /// 1.2e-5, 2.0e-6 and 5.2e-7. For `f64` types, it's about 8.1e-5, 1.1e-5, 1.7e-6, 2.5e-7,
+    /// 8.9e-10, 3.5e-12, 9.1e-14 and 4.4e-16.
+    unsafe fn atan2_fast_approx<P: Precision<Self::Scalar>>(self, x: Self) -> Self;
+    /// # Inputs
+    /// Precision can set between [`P0`] and [`P3`] for `f32` types and between [`P0`] and [`P7`]
+    /// for `f64` types, with `P0` being the fastest and least precise, and the maximum being the
+    /// slowest and most precise.
+    ///
+    /// # Safety
+    /// Inputs valid between [-1, 1]. The output of this function can differ based on
+    /// machine characteristics, and should not be used with equality testing.
+    ///
+    /// # Notes
+    /// This is calculated as `PI/2 - acos(self)`, so the error is absolute rather than relative
+    /// near 0. For `f32` types, the maximum absolute error for each precision level is about
+    /// 3.9e-5, 5.1e-6, 9.7e-7 and 4.3e-7. For `f64` types, it's about 3.8e-5, 4.8e-6, 6.4e-7,
+    /// 8.8e-8, 2.7e-10, 6.0e-12, 2.3e-14 and 8.9e-16.
+    unsafe fn asin_fast_approx<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Precision can set between [`P0`] and [`P3`] for `f32` types and between [`P0`] and [`P7`]
+    /// for `f64` types, with `P0` being the fastest and least precise, and the maximum being the
+    /// slowest and most precise.
+    ///
+    /// # Safety
+    /// Inputs valid between [-1, 1]. The output of this function can differ based on
+    /// machine characteristics, and should not be used with equality testing.
+    ///
+    /// # Notes
+    /// For `f32` types, the maximum absolute error for each precision level is about 3.9e-5,
+    /// 5.1e-6, 9.7e-7 and 4.3e-7. For `f64` types, it's about 3.8e-5, 4.8e-6, 6.4e-7, 8.8e-8,
+    /// 2.7e-10, 6.0e-12, 2.3e-14 and 8.9e-16.
+    unsafe fn acos_fast_approx<P: Precision<Self::Scalar>>(self) -> Self;
+
+    /// # Safety
+    /// Inputs valid between (0, Infinity). The output of this function can differ based on
+    /// machine characteristics, and should not be used with equality testing.
+    ///
+    /// # Notes
+    /// This is the fastest log function in the library, and is much faster than doing a
+    /// variable-base calculation with `2.0`.
+    unsafe fn log2_fast_approx<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Safety
+    /// Inputs valid between (0, Infinity). The output of this function can differ based on
+    /// machine characteristics, and should not be used with equality testing.
+    ///
+    /// # Notes
+    /// This function is faster than doing a variable-base calculation with `10.0`.
+    unsafe fn log10_fast_approx<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Safety
+    /// Inputs valid between (0, Infinity). The output of this function can differ based on
+    /// machine characteristics, and should not be used with equality testing.
+    ///
+    /// # Notes
+    /// This function is faster than doing a variable-base calculation with `E`.
+    unsafe fn ln_fast_approx<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Safety
+    /// Inputs valid between (0, Infinity). The output of this function can differ based on
+    /// machine characteristics, and should not be used with equality testing.
+    unsafe fn log_fast_approx<P: Precision<Self::Scalar>>(self, base: Self) -> Self;
+
+    /// # Inputs
+    /// Precision can set between [`P0`] and [`P3`] for `f32` types and between [`P0`] and [`P7`]
+    /// for `f64` types, with `P0` being the fastest and least precise, and the maximum being the
+    /// slowest and most precise.
+    ///
+    /// # Safety
+    /// Inputs valid between [-125, 127] for `f32` types and [-1021, 1023] for `f64` types. The
+    /// output of this function can differ based on machine characteristics, and should not be
+    /// used with equality testing.
+    ///
+    /// # Notes
+    /// This is the fastest exp function in the library, and the other exp functions are scaled
+    /// versions of it.
+    unsafe fn exp2_fast_approx<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Precision can set between [`P0`] and [`P3`] for `f32` types and between [`P0`] and [`P7`]
+    /// for `f64` types, with `P0` being the fastest and least precise, and the maximum being the
+    /// slowest and most precise.
+    ///
+    /// # Safety
+    /// Inputs valid between [-86, 88] for `f32` types and [-708, 709] for `f64` types. The
+    /// output of this function can differ based on machine characteristics, and should not be
+    /// used with equality testing.
+    unsafe fn exp_fast_approx<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Precision can set between [`P0`] and [`P3`] for `f32` types and between [`P0`] and [`P7`]
+    /// for `f64` types, with `P0` being the fastest and least precise, and the maximum being the
+    /// slowest and most precise.
+    ///
+    /// # Safety
+    /// Inputs valid between [-37, 38] for `f32` types and [-307, 308] for `f64` types. The
+    /// output of this function can differ based on machine characteristics, and should not be
+    /// used with equality testing.
+    unsafe fn exp10_fast_approx<P: Precision<Self::Scalar>>(self) -> Self;
+
+    /// # Inputs
+    /// Precision can set between [`P0`] and [`P3`] for `f32` types and between [`P0`] and [`P7`]
+    /// for `f64` types, with `P0` being the fastest and least precise, and the maximum being the
+    /// slowest and most precise.
+    ///
+    /// # Safety
+    /// Inputs valid between (0, Infinity), where `exponent * log2(self)` is between [-125, 127]
+    /// for `f32` types and [-1021, 1023] for `f64` types. The output of this function can differ
+    /// based on machine characteristics, and should not be used with equality testing.
+    ///
+    /// # Notes
+    /// This is calculated as `exp2(exponent * log2(self))`, so the error of the log2 approximation
+    /// gets scaled by the exponent.
+    unsafe fn pow_fast_approx<P: Precision<Self::Scalar>>(self, exponent: Self) -> Self;
+    /// # Safety
+    /// Inputs valid where the input is finite, and `self^|EXPONENT|` doesn't overflow to
+    /// infinity. For negative exponents, the input also can't be 0, because the reciprocal of the
+    /// result would be infinite. The output of this function can differ based on machine
+    /// characteristics, and should not be used with equality testing.
+    ///
+    /// # Notes
+    /// This is calculated with repeated squaring, which is fully unrolled for the `const`
+    /// exponent. Negative exponents take the reciprocal of the result. The intermediate squares
+    /// never have a larger magnitude than `self^|EXPONENT|` or `self`, so they stay finite too.
+    unsafe fn powi_const<const EXPONENT: i32>(self) -> Self;
+    /// Raises the input to the power of `NUMERATOR / DENOMINATOR`.
+    ///
+    /// # Inputs
+    /// Precision can set between [`P0`] and [`P3`] for `f32` types and between [`P0`] and [`P7`]
+    /// for `f64` types, with `P0` being the fastest and least precise, and the maximum being the
+    /// slowest and most precise.
+    ///
+    /// # Safety
+    /// Inputs valid between (0, Infinity), where `(NUMERATOR / DENOMINATOR) * log2(self)` is
+    /// between [-125, 127] for `f32` types and [-1021, 1023] for `f64` types. The output of this
+    /// function can differ based on machine characteristics, and should not be used with equality
+    /// testing.
+    ///
+    /// # Notes
+    /// If `DENOMINATOR` is 1, this uses `powi_const` instead, which has the input restrictions of
+    /// `powi_const` rather than the ones above.
+    unsafe fn powf_const<P: Precision<Self::Scalar>, const NUMERATOR: i32, const DENOMINATOR: u32>(
+        self,
+    ) -> Self;
+
+    /// # Inputs
+    /// Precision can set between [`P0`] and [`P3`] for `f32` types and between [`P0`] and [`P7`]
+    /// for `f64` types, with `P0` being the fastest and least precise, and the maximum being the
+    /// slowest and most precise.
+    ///
+    /// # Safety
+    /// Inputs valid between [-86, 86] for `f32` types and [-708, 708] for `f64` types. The output
+    /// of this function can differ based on machine characteristics, and should not be used with
+    /// equality testing.
+    ///
+    /// # Notes
+    /// This is calculated as `(e^x - e^-x) / 2` from a single exp approximation. Between (-1, 1),
+    /// where the subtraction would cancel out most of the value, an odd polynomial is used
+    /// instead, so the error stays relative and `sinh(0)` is exactly 0.
+    unsafe fn sinh_fast_approx<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Precision can set between [`P0`] and [`P3`] for `f32` types and between [`P0`] and [`P7`]
+    /// for `f64` types, with `P0` being the fastest and least precise, and the maximum being the
+    /// slowest and most precise.
+    ///
+    /// # Safety
+    /// Inputs valid between [-86, 86] for `f32` types and [-708, 708] for `f64` types. The output
+    /// of this function can differ based on machine characteristics, and should not be used with
+    /// equality testing.
+    ///
+    /// # Notes
+    /// This is calculated as `(e^x + e^-x) / 2` from a single exp approximation.
+    unsafe fn cosh_fast_approx<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Precision can set between [`P0`] and [`P3`] for `f32` types and between [`P0`] and [`P7`]
+    /// for `f64` types, with `P0` being the fastest and least precise, and the maximum being the
+    /// slowest and most precise.
+    ///
+    /// # Safety
+    /// Inputs valid between (-Infinity, Infinity). The output of this function can differ based
+    /// on machine characteristics, and should not be used with equality testing.
+    ///
+    /// # Notes
+    /// This is calculated as `1 - 2 / (e^2x + 1)`. The input is clamped before the exp
+    /// approximation, so large inputs saturate to exactly ±1. For `f32` types, the maximum
+    /// absolute error for each precision level is about 8.6e-4, 3.8e-5, 1.5e-6 and 4.0e-7. For
+    /// `f64` types, it's about 8.6e-4, 3.7e-5, 1.3e-6, 3.7e-8, 2.0e-11, 3.9e-13, 6.9e-15 and
+    /// 4.4e-16.
+    unsafe fn tanh_fast_approx<P: Precision<Self::Scalar>>(self) -> Self;
+
+    /// # Inputs
+    /// Precision is the amount of Newton iterations done after the initial guess, with 0 being
+    /// the fastest and least precise. 3 iterations for `f32` types and 4 iterations for `f64`
+    /// types reach the precision limit of the type.
+    ///
+    /// # Safety
+    /// Inputs valid between [0, Infinity). The output of this function can differ based on
+    /// machine characteristics, and should not be used with equality testing.
+    ///
+    /// # Notes
+    /// This is calculated as `self * rsqrt(self)`. The maximum relative error for each precision
+    /// level is about 3.5e-2, 1.8e-3, 4.7e-6, and then the limit of the type.
+    unsafe fn sqrt_fast_approx<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Precision is the amount of Newton iterations done after the initial guess, with 0 being
+    /// the fastest and least precise. 3 iterations for `f32` types and 4 iterations for `f64`
+    /// types reach the precision limit of the type.
+    ///
+    /// # Safety
+    /// Inputs valid between (0, Infinity). The output of this function can differ based on
+    /// machine characteristics, and should not be used with equality testing.
+    ///
+    /// # Notes
+    /// The initial guess is made by halving and negating the exponent in the bit representation.
+    /// The maximum relative error for each precision level is about 3.5e-2, 1.8e-3, 4.7e-6, and
+    /// then the limit of the type.
+    unsafe fn rsqrt_fast_approx<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Precision is the amount of Newton iterations done after the initial guess, with 0 being
+    /// the fastest and least precise. 3 iterations for `f32` types and 4 iterations for `f64`
+    /// types reach the precision limit of the type.
+    ///
+    /// # Safety
+    /// Inputs valid between (-Infinity, Infinity). The output of this function can differ based
+    /// on machine characteristics, and should not be used with equality testing.
+    ///
+    /// # Notes
+    /// This is calculated as `self * rcbrt(self)^2`, where the initial guess of `rcbrt` is made
+    /// by dividing the exponent by -3 in the bit representation. The maximum relative error for
+    /// each precision level is about 6.9e-2, 4.7e-3, 2.2e-5, and then the limit of the type.
+    unsafe fn cbrt_fast_approx<P: Precision<Self::Scalar>>(self) -> Self;
+}
+
+/// Safe versions of the functions in [`FastApproxFloat`], which are defined for every input. Inputs
+/// within the valid range of the unsafe function use the fast approximation, NaNs are propagated,
+/// and the rest of the inputs fall back to a slow path that handles them correctly. For SIMD
+/// types, only the lanes outside of the valid range take the slow path.
+///
+/// The slow path uses the most precise approximations in this crate, which are within about 4e-6
+/// of the exact results for `f32` types, so no math library needs to be linked on `no_std`
+/// targets.
+pub trait FastApproxFloatChecked: FastApproxFloat {
+    /// # Inputs
+    /// Uses the same precision levels as `sin_fast_approx`.
+    ///
+    /// # Notes
+    /// The error of the fast range reduction grows to about `|x| * EPSILON`, so it's only used
+    /// while that stays below [`Precision::SIN_MAX_ERROR`]. Larger finite inputs use the range
+    /// reduction of `sin_wide_fast_approx`, which keeps the same accuracy, and infinities return
+    /// NaN.
+    fn sin_fast_approx_checked<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Uses the same precision levels as `cos_fast_approx`.
+    ///
+    /// # Notes
+    /// Uses the same bound as `sin_fast_approx_checked`, and `cos_wide_fast_approx` past it, which
+    /// keeps the same accuracy. Infinities return NaN.
+    fn cos_fast_approx_checked<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Uses the same precision levels as `sincos_fast_approx`.
+    ///
+    /// # Notes
+    /// Uses the same bound as `sin_fast_approx_checked`, and `sincos_wide_fast_approx` past it,
+    /// which keeps the same accuracy. Infinities return NaN for both results.
+    fn sincos_fast_approx_checked<P: Precision<Self::Scalar>>(self) -> (Self, Self);
+    /// # Inputs
+    /// Uses the same precision levels as `sin_wide_fast_approx`.
+    ///
+    /// # Notes
+    /// Every finite input takes the fast path, with the same accuracy as `sin_wide_fast_approx`.
+    /// Infinities return NaN.
+    fn sin_wide_fast_approx_checked<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Uses the same precision levels as `cos_wide_fast_approx`.
+    ///
+    /// # Notes
+    /// Every finite input takes the fast path, with the same accuracy as `cos_wide_fast_approx`.
+    /// Infinities return NaN.
+    fn cos_wide_fast_approx_checked<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Uses the same precision levels as `sincos_wide_fast_approx`.
+    ///
+    /// # Notes
+    /// Every finite input takes the fast path, with the same accuracy as
+    /// `sincos_wide_fast_approx`. Infinities return NaN for both results.
+    fn sincos_wide_fast_approx_checked<P: Precision<Self::Scalar>>(self) -> (Self, Self);
+    /// # Inputs
+    /// Uses the same precision levels as `sin_restrict_fast_approx`.
+    ///
+    /// # Notes
+    /// Inputs outside of [-PI/2, PI/2] fall back to `sin_fast_approx_checked`, so they have the
+    /// accuracy of `sin_fast_approx` instead, and infinities return NaN.
+    fn sin_restrict_fast_approx_checked<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Uses the same precision levels as `cos_restrict_fast_approx`.
+    ///
+    /// # Notes
+    /// Inputs outside of [-PI/2, PI/2] fall back to `cos_fast_approx_checked`, so they have the
+    /// accuracy of `cos_fast_approx` instead, and infinities return NaN.
+    fn cos_restrict_fast_approx_checked<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Uses the same precision levels as `tan_fast_approx`.
+    ///
+    /// # Notes
+    /// Uses the same bound as `sin_fast_approx_checked`, and divides the results of
+    /// `sincos_wide_fast_approx` past it. The slow path doesn't saturate near the poles, so its
+    /// results can be larger than the saturated magnitude of `tan_fast_approx`. Infinities return
+    /// NaN.
+    fn tan_fast_approx_checked<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Uses the same precision levels as `tan_restrict_fast_approx`.
+    ///
+    /// # Notes
+    /// Inputs outside of [-PI/2, PI/2] fall back to `tan_fast_approx_checked`, and infinities
+    /// return NaN.
+    fn tan_restrict_fast_approx_checked<P: Precision<Self::Scalar>>(self) -> Self;
+
+    /// # Inputs
+    /// Uses the same precision levels as `atan_fast_approx`.
+    ///
+    /// # Notes
+    /// Every finite input takes the fast path, with the same accuracy as `atan_fast_approx`.
+    /// Infinite inputs map to ±PI/2.
+    fn atan_fast_approx_checked<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Uses the same precision levels as `atan2_fast_approx`.
+    ///
+    /// # Notes
+    /// Zeros and infinities map to the same angles as the standard library's `atan2`, and NaN in
+    /// either input is propagated. When both inputs are subnormal, they're scaled by the same
+    /// power of 2 before the fast path, which doesn't change the angle, so they keep its accuracy.
+    fn atan2_fast_approx_checked<P: Precision<Self::Scalar>>(self, x: Self) -> Self;
+    /// # Inputs
+    /// Uses the same precision levels as `asin_fast_approx`.
+    ///
+    /// # Notes
+    /// Inputs outside of [-1, 1] return NaN, and the rest take the fast path, with the same
+    /// accuracy as `asin_fast_approx`.
+    fn asin_fast_approx_checked<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Uses the same precision levels as `acos_fast_approx`.
+    ///
+    /// # Notes
+    /// Inputs outside of [-1, 1] return NaN, and the rest take the fast path, with the same
+    /// accuracy as `acos_fast_approx`.
+    fn acos_fast_approx_checked<P: Precision<Self::Scalar>>(self) -> Self;
+
+    /// # Inputs
+    /// Uses the same precision levels as `log2_fast_approx`.
+    ///
+    /// # Notes
+    /// Subnormal inputs also take the slow path, because the fast path reads the exponent bits.
+    /// They're scaled into the normal range first, and are as accurate as the most precise level
+    /// of `log2_fast_approx`. 0 returns -Infinity, Infinity returns itself, and negative inputs
+    /// return NaN.
+    fn log2_fast_approx_checked<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Uses the same precision levels as `log10_fast_approx`.
+    ///
+    /// # Notes
+    /// Subnormal inputs also take the slow path, because the fast path reads the exponent bits.
+    /// The slow path scales the most precise level of `log2_fast_approx`, and returns the same
+    /// special values as `log2_fast_approx_checked`.
+    fn log10_fast_approx_checked<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Uses the same precision levels as `ln_fast_approx`.
+    ///
+    /// # Notes
+    /// Subnormal inputs also take the slow path, because the fast path reads the exponent bits.
+    /// The slow path scales the most precise level of `log2_fast_approx`, and returns the same
+    /// special values as `log2_fast_approx_checked`.
+    fn ln_fast_approx_checked<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Uses the same precision levels as `log_fast_approx`.
+    ///
+    /// # Notes
+    /// When the input or the base is outside of (0, Infinity) or subnormal, the slow path divides
+    /// the results of the `log2_fast_approx_checked` slow path for both. Negative inputs or bases
+    /// return NaN, and the other special values follow from the division, like -Infinity for an
+    /// input of 0 with a base above 1.
+    fn log_fast_approx_checked<P: Precision<Self::Scalar>>(self, base: Self) -> Self;
+
+    /// # Inputs
+    /// Uses the same precision levels as `exp2_fast_approx`.
+    ///
+    /// # Notes
+    /// Inputs outside of the valid range of `exp2_fast_approx` take the slow path. Results that
+    /// overflow return Infinity, and results that are too small return 0. Subnormal results are
+    /// calculated from a shifted input and rounded once, and are otherwise as accurate as the
+    /// most precise level of `exp2_fast_approx`.
+    fn exp2_fast_approx_checked<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Uses the same precision levels as `exp_fast_approx`.
+    ///
+    /// # Notes
+    /// Inputs outside of the valid range of `exp_fast_approx` take the slow path, which returns
+    /// Infinity and 0 past the limits of the type the same as `exp2_fast_approx_checked`. The
+    /// multiple of `ln(2)` is split off exactly, so the slow path is as accurate as the most
+    /// precise level of `exp2_fast_approx`.
+    fn exp_fast_approx_checked<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Uses the same precision levels as `exp10_fast_approx`.
+    ///
+    /// # Notes
+    /// Inputs outside of the valid range of `exp10_fast_approx` take the slow path, which is
+    /// calculated as `pow(10, self)` and returns Infinity and 0 past the limits of the type. The
+    /// error of the slow path grows with the input, the same as `pow_fast_approx`.
+    fn exp10_fast_approx_checked<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Uses the same precision levels as `pow_fast_approx`.
+    ///
+    /// # Notes
+    /// The fast path is only taken for normal positive inputs where `exponent * log2(self)` is in
+    /// the valid range of `exp2_fast_approx`. The slow path handles zeros, infinities and negative
+    /// inputs the same as the standard library's `powf`, returning NaN for negative inputs with a
+    /// non-integer exponent. It uses the most precise levels of `log2` and `exp2`, so the error of
+    /// the log2 is still scaled by the exponent.
+    fn pow_fast_approx_checked<P: Precision<Self::Scalar>>(self, exponent: Self) -> Self;
+    /// # Notes
+    /// The fast path is only taken when the exponent bits of the input show that the result
+    /// can't overflow or underflow. The slow path does the same repeated squaring without
+    /// unrolling, so it overflows to Infinity and underflows to 0 the same as multiplying the
+    /// input that many times. An `EXPONENT` of 0 always returns 1.
+    fn powi_const_checked<const EXPONENT: i32>(self) -> Self;
+    /// # Inputs
+    /// Uses the same precision levels as `powf_const`.
+    ///
+    /// # Panics
+    /// Panics when `DENOMINATOR` is 0.
+    ///
+    /// # Notes
+    /// If `DENOMINATOR` is 1, this uses `powi_const_checked`, and otherwise it uses
+    /// `pow_fast_approx_checked` with an exponent of `NUMERATOR / DENOMINATOR`.
+    fn powf_const_checked<
+        P: Precision<Self::Scalar>,
+        const NUMERATOR: i32,
+        const DENOMINATOR: u32,
+    >(
+        self,
+    ) -> Self;
+
+    /// # Inputs
+    /// Uses the same precision levels as `sinh_fast_approx`.
+    ///
+    /// # Notes
+    /// Inputs past the valid range of `sinh_fast_approx` take the slow path, where `e^-x` is
+    /// negligible. It squares `e^(|x| / 2)` from the most precise level of `exp_fast_approx`, so
+    /// the result only overflows to ±Infinity where the exact result does, and the relative error
+    /// is about twice the error of that level.
+    fn sinh_fast_approx_checked<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Uses the same precision levels as `cosh_fast_approx`.
+    ///
+    /// # Notes
+    /// Uses the same slow path as `sinh_fast_approx_checked`, but the result is always positive,
+    /// so large inputs overflow to Infinity.
+    fn cosh_fast_approx_checked<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Uses the same precision levels as `tanh_fast_approx`.
+    ///
+    /// # Notes
+    /// Every finite input takes the fast path, with the same accuracy as `tanh_fast_approx`.
+    /// Infinite inputs map to ±1.
+    fn tanh_fast_approx_checked<P: Precision<Self::Scalar>>(self) -> Self;
+
+    /// # Inputs
+    /// Uses the same precision levels as `sqrt_fast_approx`.
+    ///
+    /// # Notes
+    /// Every input between [0, Infinity) takes the fast path, with the same accuracy as
+    /// `sqrt_fast_approx`. Infinity returns itself, and negative inputs return NaN.
+    fn sqrt_fast_approx_checked<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Uses the same precision levels as `rsqrt_fast_approx`.
+    ///
+    /// # Notes
+    /// Every input between (0, Infinity) takes the fast path, with the same accuracy as
+    /// `rsqrt_fast_approx`. The rest of the inputs return the same as `1 / sqrt(self)`: zeros
+    /// return Infinity with the same sign, Infinity returns 0, and negative inputs return NaN.
+    fn rsqrt_fast_approx_checked<P: Precision<Self::Scalar>>(self) -> Self;
+    /// # Inputs
+    /// Uses the same precision levels as `cbrt_fast_approx`.
+    ///
+    /// # Notes
+    /// Every finite input takes the fast path, including negative ones, with the same accuracy as
+    /// `cbrt_fast_approx`. Infinities return themselves.
+    fn cbrt_fast_approx_checked<P: Precision<Self::Scalar>>(self) -> Self;
+}
+
+pub(crate) mod double;
+mod kernels;
+pub(crate) mod precision;
+pub(crate) mod single;
+
+pub use precision::{Accurate, Fastest, Precision, P0, P1, P2, P3, P4, P5, P6, P7};
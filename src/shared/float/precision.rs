@@ -0,0 +1,54 @@
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A precision level for the functions in [`FastApproxFloat`](super::FastApproxFloat), where `F`
+/// is the scalar float type. `f32` types have the levels [`P0`] to [`P3`], and `f64` types have
+/// the levels [`P0`] to [`P7`], so using a level that the type doesn't have is a compile error.
+///
+/// This trait is sealed, and can't be implemented outside of this crate.
+pub trait Precision<F>: sealed::Sealed {
+    /// The index of the level, which selects the coefficients or the amount of iterations.
+    const LEVEL: usize;
+    /// The maximum absolute error of `sin_fast_approx` and `cos_fast_approx` for inputs between
+    /// [-PI/2, PI/2].
+    const SIN_MAX_ERROR: F;
+}
+
+macro_rules! precision_impl {
+    ($name:ident,$level:expr,$f32_error:expr,$f64_error:expr) => {
+        precision_impl!($name, $level, $f64_error);
+
+        impl Precision<f32> for $name {
+            const LEVEL: usize = $level;
+            const SIN_MAX_ERROR: f32 = $f32_error;
+        }
+    };
+    ($name:ident,$level:expr,$f64_error:expr) => {
+        #[doc = concat!("Precision level ", stringify!($level), ".")]
+        #[derive(Clone, Copy, Debug)]
+        pub enum $name {}
+
+        impl sealed::Sealed for $name {}
+
+        impl Precision<f64> for $name {
+            const LEVEL: usize = $level;
+            const SIN_MAX_ERROR: f64 = $f64_error;
+        }
+    };
+}
+
+precision_impl!(P0, 0, 2.9e-2_f32, 2.9e-2_f64);
+precision_impl!(P1, 1, 6.0e-4_f32, 6.0e-4_f64);
+precision_impl!(P2, 2, 7.2e-6_f32, 6.9e-6_f64);
+precision_impl!(P3, 3, 3.2e-7_f32, 4.7e-8_f64);
+precision_impl!(P4, 4, 2.3e-10_f64);
+precision_impl!(P5, 5, 7.8e-13_f64);
+precision_impl!(P6, 6, 2.6e-15_f64);
+precision_impl!(P7, 7, 6.0e-16_f64);
+
+/// The fastest and least precise level, which every float type has.
+pub type Fastest = P0;
+/// The most precise level that every float type has. `f64` types have more precise levels up to
+/// [`P7`].
+pub type Accurate = P3;
@@ -0,0 +1,291 @@
+use crate::shared::float::kernels::float_kernels;
+
+// Coefficients were generated from this Julia function:
+// https://gist.github.com/burgerindividual/5f0ee20232f78c356df5767713ffad57
+//
+// The `sin` and `cos` coefficients were derived from here:
+// https://publik-void.github.io/sin-cos-approximations/#_cos_abs_error_minimized_degree_2
+
+/// Subtracting half of the input's bits from this gives the initial guess for `1/sqrt(x)`.
+pub(crate) const RSQRT_MAGIC: u32 = 0x5f37_5a86;
+/// Subtracting a third of the input's bits from this gives the initial guess for `1/cbrt(x)`.
+pub(crate) const RCBRT_MAGIC: u32 = 0x54a2_1e08;
+
+/// The bounds that `exp2` is valid between.
+pub(crate) const EXP2_BOUNDS: (f32, f32) = (-125.0, 127.0);
+/// The bounds that `exp` is valid between.
+pub(crate) const EXP_BOUNDS: (f32, f32) = (-86.0, 88.0);
+/// The bounds that `exp10` is valid between.
+pub(crate) const EXP10_BOUNDS: (f32, f32) = (-37.0, 38.0);
+/// The largest magnitude that `sinh` and `cosh` are valid for.
+pub(crate) const HYPERBOLIC_MAX: f32 = 86.0;
+/// The largest magnitude that the Cody-Waite reduction in the `wide` trig functions is used for.
+/// Larger inputs use the Payne-Hanek reduction instead.
+pub(crate) const CODY_WAITE_MAX: f32 = 8_192.0;
+/// PI split into parts for the Cody-Waite reduction. The first two parts have enough trailing zeros
+/// that multiplying them by any multiple of PI up to `CODY_WAITE_MAX` is exact.
+#[allow(clippy::approx_constant)]
+pub(crate) const PI_SPLIT: [f32; 3] = [3.140625_f32, 0.0009675026_f32, 1.509958e-7_f32];
+/// PI in fixed point with 2 integer bits, used by the Payne-Hanek reduction.
+pub(crate) const PI_FIXED: u32 = 0xc90f_daa2;
+
+float_kernels!(f32, u32, i32, u64, i64, nearbyintf32, sqrtf32, 23, 0.0);
+
+/// Coefficients for `cos(x * PI)` between [-0.5, 0.5], in terms of `x^2`.
+#[inline(always)]
+pub(crate) fn cos_pi_fraction_coeffs<P: Precision<f32>>() -> &'static [f32] {
+    match P::LEVEL {
+        0 => &[-4.0_f32, 0.9719952_f32],
+        1 => &[3.5838444_f32, -4.8911867_f32, 0.99940324_f32],
+        2 => &[-1.2221271_f32, 4.0412836_f32, -4.933938_f32, 0.9999933_f32],
+        3 => &[
+            0.2196968_f32,
+            -1.3318802_f32,
+            4.058412_f32,
+            -4.934793_f32,
+            0.99999994_f32,
+        ],
+        _ => unreachable!(),
+    }
+}
+
+/// Coefficients for `sin(x * PI) / x` between [-0.5, 0.5], in terms of `x^2`.
+#[inline(always)]
+#[allow(clippy::approx_constant)]
+pub(crate) fn sin_pi_fraction_coeffs<P: Precision<f32>>() -> &'static [f32] {
+    match P::LEVEL {
+        0 => &[-4.4204636_f32, 3.0961323_f32],
+        1 => &[2.2995474_f32, -5.136905_f32, 3.14064_f32],
+        2 => &[-0.5546362_f32, 2.541899_f32, -5.167143_f32, 3.141582_f32],
+        3 => &[
+            0.07722013_f32,
+            -0.5980452_f32,
+            2.5500314_f32,
+            -5.167707_f32,
+            3.1415925_f32,
+        ],
+        _ => unreachable!(),
+    }
+}
+
+/// Coefficients for `cos(x)` between [-PI/2, PI/2], in terms of `x^2`.
+#[inline(always)]
+pub(crate) fn cos_restrict_coeffs<P: Precision<f32>>() -> &'static [f32] {
+    match P::LEVEL {
+        0 => &[-0.40528473_f32, 0.9719952_f32],
+        1 => &[0.036791682_f32, -0.49558085_f32, 0.99940324_f32],
+        2 => &[
+            -0.0012712094_f32,
+            0.04148775_f32,
+            -0.49991244_f32,
+            0.9999933_f32,
+        ],
+        3 => &[
+            2.3153932e-5_f32,
+            -0.0013853704_f32,
+            0.041663583_f32,
+            -0.49999905_f32,
+            0.99999994_f32,
+        ],
+        _ => unreachable!(),
+    }
+}
+
+/// Coefficients for `sin(x) / x` between [-PI/2, PI/2], in terms of `x^2`.
+#[inline(always)]
+pub(crate) fn sin_restrict_coeffs<P: Precision<f32>>() -> &'static [f32] {
+    match P::LEVEL {
+        0 => &[-0.14256673_f32, 0.98552954_f32],
+        1 => &[0.007514377_f32, -0.16567308_f32, 0.9996968_f32],
+        2 => &[
+            -0.00018363654_f32,
+            0.008306325_f32,
+            -0.16664828_f32,
+            0.9999966_f32,
+        ],
+        3 => &[
+            2.5904885e-6_f32,
+            -0.00019800897_f32,
+            0.0083329_f32,
+            -0.16666648_f32,
+            1.0_f32,
+        ],
+        _ => unreachable!(),
+    }
+}
+
+/// Coefficients for `tan(x * PI/2) / x` between [-0.5, 0.5], in terms of `x^2`.
+#[inline(always)]
+#[allow(clippy::approx_constant)]
+pub(crate) fn tan_half_pi_fraction_coeffs<P: Precision<f32>>() -> &'static [f32] {
+    match P::LEVEL {
+        0 => &[1.9352393_f32, 1.224222_f32, 1.5717622_f32],
+        1 => &[2.2211092_f32, 1.1090906_f32, 1.3006469_f32, 1.570727_f32],
+        2 => &[
+            2.5512788_f32,
+            0.9532417_f32,
+            1.3055301_f32,
+            1.2909455_f32,
+            1.5708013_f32,
+        ],
+        3 => &[
+            2.9307532_f32,
+            0.728486_f32,
+            1.3495072_f32,
+            1.2704368_f32,
+            1.2920301_f32,
+            1.570796_f32,
+        ],
+        _ => unreachable!(),
+    }
+}
+
+/// Coefficients for `atan(x) / x` between [0, 1], in terms of `x^2`.
+#[inline(always)]
+pub(crate) fn atan_restrict_coeffs<P: Precision<f32>>() -> &'static [f32] {
+    match P::LEVEL {
+        0 => &[
+            -0.03898651_f32,
+            0.14626446_f32,
+            -0.32117498_f32,
+            0.9992138_f32,
+        ],
+        1 => &[
+            0.020845113_f32,
+            -0.08515635_f32,
+            0.1801593_f32,
+            -0.33030477_f32,
+            0.9998663_f32,
+        ],
+        2 => &[
+            -0.011719125_f32,
+            0.052647322_f32,
+            -0.116426446_f32,
+            0.19354036_f32,
+            -0.33262283_f32,
+            0.99997723_f32,
+        ],
+        3 => &[
+            0.006811792_f32,
+            -0.033604216_f32,
+            0.07962367_f32,
+            -0.13233341_f32,
+            0.19807816_f32,
+            -0.3331737_f32,
+            0.9999961_f32,
+        ],
+        _ => unreachable!(),
+    }
+}
+
+/// Coefficients for `acos(x) / sqrt(1 - x)` between [0, 1].
+#[inline(always)]
+#[allow(clippy::approx_constant)]
+pub(crate) fn acos_coeffs<P: Precision<f32>>() -> &'static [f32] {
+    match P::LEVEL {
+        0 => &[
+            -0.020892037_f32,
+            0.07689739_f32,
+            -0.21287519_f32,
+            1.5707583_f32,
+        ],
+        1 => &[
+            0.00973297_f32,
+            -0.03761822_f32,
+            0.08563838_f32,
+            -0.2142806_f32,
+            1.5707915_f32,
+        ],
+        2 => &[
+            -0.0049111764_f32,
+            0.020620067_f32,
+            -0.04592723_f32,
+            0.08817106_f32,
+            -0.21454282_f32,
+            1.5707957_f32,
+        ],
+        3 => &[
+            0.0026117223_f32,
+            -0.012003399_f32,
+            0.027762918_f32,
+            -0.04919744_f32,
+            0.08883589_f32,
+            -0.21459109_f32,
+            1.5707963_f32,
+        ],
+        _ => unreachable!(),
+    }
+}
+
+/// Coefficients for `log2(x)` between [1, 2).
+#[inline(always)]
+pub(crate) fn log2_coeffs<P: Precision<f32>>() -> &'static [f32] {
+    match P::LEVEL {
+        0 => &[-0.34484842_f32, 2.0246658_f32, -1.6748776_f32],
+        1 => &[0.15824871_f32, -1.051875_f32, 3.0478842_f32, -2.1536207_f32],
+        2 => &[
+            -0.081615806_f32,
+            0.6451424_f32,
+            -2.120675_f32,
+            4.070091_f32,
+            -2.5128546_f32,
+        ],
+        3 => &[
+            0.04487361_f32,
+            -0.4165637_f32,
+            1.6311488_f32,
+            -3.550793_f32,
+            5.091711_f32,
+            -2.800364_f32,
+        ],
+        _ => unreachable!(),
+    }
+}
+
+/// Coefficients for `2^x` between [-0.5, 0.5].
+#[inline(always)]
+pub(crate) fn exp2_coeffs<P: Precision<f32>>() -> &'static [f32] {
+    match P::LEVEL {
+        0 => &[0.23842894_f32, 0.703448_f32, 1.0004431_f32],
+        1 => &[
+            0.05517167_f32,
+            0.24261113_f32,
+            0.69326097_f32,
+            0.99992806_f32,
+        ],
+        2 => &[
+            0.009570102_f32,
+            0.05591786_f32,
+            0.24024744_f32,
+            0.6931218_f32,
+            0.9999993_f32,
+        ],
+        3 => &[
+            0.0013276472_f32,
+            0.009675541_f32,
+            0.05550713_f32,
+            0.2402212_f32,
+            0.69314694_f32,
+            1.0000001_f32,
+        ],
+        _ => unreachable!(),
+    }
+}
+
+/// Coefficients for `sinh(x) / x` between [-1, 1], in terms of `x^2`.
+#[inline(always)]
+pub(crate) fn sinh_coeffs<P: Precision<f32>>() -> &'static [f32] {
+    match P::LEVEL {
+        0 => &[0.17502692_f32, 0.9990053_f32],
+        1 => &[0.008631883_f32, 0.16655654_f32, 1.0000058_f32],
+        2 => &[0.0002039399_f32, 0.008329912_f32, 0.16666734_f32, 1.0_f32],
+        3 => &[
+            2.8185239e-6_f32,
+            0.00019835813_f32,
+            0.008333352_f32,
+            0.16666667_f32,
+            1.0_f32,
+        ],
+        _ => unreachable!(),
+    }
+}
@@ -28,10 +28,44 @@ macro_rules! ilog_mul_shift {
             b >>= 1;
         }
 
-        let multiplier = (((numerator as u128) << PRECISION) / (result as u128)) as $u;
+        // (numerator << PRECISION) / result, split up so the shift can't overflow for 128-bit
+        // types. the remainder is less than the 64-bit result, so its shift always fits.
+        let quotient = numerator as u128 / result as u128;
+        let remainder = numerator as u128 % result as u128;
+        let multiplier =
+            ((quotient << PRECISION) + ((remainder << PRECISION) / result as u128)) as $u;
 
         (multiplier, shift)
     }};
 }
 
 pub(crate) use ilog_mul_shift;
+
+// Adapted from the round-up method in "Division by Invariant Integers using Multiplication" by
+// Granlund and Montgomery. The quotient is (t + ((x - t) >> 1)) >> (shift - 1), where t is the
+// high half of x * multiplier.
+macro_rules! div_mul_shift {
+    ($u:ty,$divisor:ident) => {{
+        let divisor = $divisor as u128;
+        // ceil(log2(divisor))
+        let shift: $u = (u128::BITS - (divisor - 1).leading_zeros()) as $u;
+
+        // (((1 << shift) - divisor) << BITS) / divisor + 1, done with long division so the
+        // numerator can't overflow for 128-bit types. the remainder is always less than twice
+        // the divisor, so it fits.
+        let mut remainder = (1_u128 << shift) - divisor;
+        let mut multiplier: $u = 0;
+        for _i in 0..<$u>::BITS {
+            remainder <<= 1;
+            multiplier <<= 1;
+            if remainder >= divisor {
+                remainder -= divisor;
+                multiplier |= 1;
+            }
+        }
+
+        (multiplier.wrapping_add(1), shift)
+    }};
+}
+
+pub(crate) use div_mul_shift;
@@ -0,0 +1,47 @@
+use crate::shared::int::consts::div_mul_shift;
+
+/// An unsigned divisor that's only known at runtime, with a precomputed multiplier and shifts.
+/// Dividing a scalar or SIMD type by this replaces the division with a multiply-high, a
+/// subtraction and shifts, which is worth the setup when dividing by the same value many times.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FastDivisor<T> {
+    pub(crate) divisor: T,
+    pub(crate) multiplier: T,
+    pub(crate) pre_shift: T,
+    pub(crate) post_shift: T,
+}
+
+macro_rules! divisor_impl {
+    ($u:ty) => {
+        impl FastDivisor<$u> {
+            /// # Panics
+            /// Panics when the divisor is 0.
+            #[inline(always)]
+            pub fn new(divisor: $u) -> Self {
+                assert!(divisor != 0, "invalid divisor: 0");
+                let mul_shift = div_mul_shift!($u, divisor);
+
+                // a divisor of 1 has a shift of 0, which can't be split into a shift before and
+                // after the addition. the multiplier is 1, so the high half is always 0, and the
+                // quotient is the unshifted input.
+                Self {
+                    divisor,
+                    multiplier: mul_shift.0,
+                    pre_shift: mul_shift.1.min(1),
+                    post_shift: mul_shift.1.max(1) - 1,
+                }
+            }
+
+            #[inline(always)]
+            pub const fn divisor(&self) -> $u {
+                self.divisor
+            }
+        }
+    };
+}
+
+divisor_impl!(u8);
+divisor_impl!(u16);
+divisor_impl!(u32);
+divisor_impl!(u64);
+divisor_impl!(usize);
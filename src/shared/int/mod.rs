@@ -1,12 +1,144 @@
 pub mod consts;
+mod divisor;
+
+pub use divisor::FastDivisor;
 
 /// Defines exact-valued functions. If a function is marked as `unsafe`, the value of the function
 /// is exact within the listed constraints for safety.
 pub trait FastExactInt {
+    /// The result of checked operations. For scalar types, this is an `Option`, which is `None`
+    /// when the operation fails. For SIMD types and arrays, this is the result paired with a mask
+    /// that is set for each lane that didn't fail, and the values in failed lanes shouldn't be
+    /// relied on.
+    type Checked;
+
+    /// # Panics
+    /// Panics when the input is less than 1, which includes negative inputs for signed types.
     fn ilog_const_base<const BASE: u32>(self) -> Self;
     /// # Safety
-    /// Inputs valid when != 0.
+    /// Inputs valid when > 0.
     unsafe fn ilog_const_base_unchecked<const BASE: u32>(self) -> Self;
+    /// Calculates the floor of the log of the input, failing when the input is less than 1.
+    ///
+    /// # Panics
+    /// Panics when the base is invalid, because it's known at compile time.
+    fn checked_ilog_const_base<const BASE: u32>(self) -> Self::Checked;
 
+    /// Calculates the floor of the log of the input, with a base that's only known at runtime.
+    ///
+    /// # Panics
+    /// Panics when the input is less than 1, or when the base is less than 2.
+    fn ilog_var_base(self, base: Self) -> Self;
+    /// # Safety
+    /// Inputs valid when > 0, and bases valid when > 1.
+    ///
+    /// # Notes
+    /// This estimates the log with float log2 approximations. For scalar types, the estimate is
+    /// only corrected by comparing against an integer power of the base when it's close to an
+    /// integer. SIMD types correct every lane, so there's no branch on the inputs.
+    unsafe fn ilog_var_base_unchecked(self, base: Self) -> Self;
+
+    /// Calculates `COEFF^self`.
+    ///
+    /// # Panics
+    /// Panics when the exponent is negative for signed types, because the result wouldn't be an
+    /// integer. Scalar types also panic when the result overflows.
+    ///
+    /// # Notes
+    /// The coefficient is unsigned, so the result is never negative, even for odd exponents on
+    /// signed types. [`FastExactSignedInt::exp_const_signed_coeff`] takes a signed coefficient.
     fn exp_const_coeff<const COEFF: u32>(self) -> Self;
+    /// Calculates `COEFF^self`, failing when the result overflows or when the exponent is
+    /// negative for signed types.
+    fn checked_exp_const_coeff<const COEFF: u32>(self) -> Self::Checked;
+    /// Calculates `COEFF^self`, saturating at the maximum of the type.
+    ///
+    /// # Panics
+    /// Panics when the exponent is negative for signed types.
+    fn saturating_exp_const_coeff<const COEFF: u32>(self) -> Self;
+
+    /// Calculates `self^exp`, with an exponent that's only known at runtime.
+    ///
+    /// # Panics
+    /// Panics when the result overflows, or when the exponent is negative for signed types.
+    ///
+    /// # Notes
+    /// For SIMD types, each lane is multiplied by the power at each bit of its own exponent, so
+    /// lanes with different exponents still vectorize.
+    fn ipow(self, exp: Self) -> Self;
+    /// Calculates `self^exp`, wrapping around at the bounds of the type.
+    ///
+    /// # Panics
+    /// Panics when the exponent is negative for signed types.
+    fn wrapping_ipow(self, exp: Self) -> Self;
+    /// Calculates `self^exp`, failing when the result overflows or when the exponent is negative
+    /// for signed types.
+    fn checked_ipow(self, exp: Self) -> Self::Checked;
+    /// Calculates `self^exp`, saturating at the bounds of the type.
+    ///
+    /// # Panics
+    /// Panics when the exponent is negative for signed types.
+    fn saturating_ipow(self, exp: Self) -> Self;
+
+    /// Calculates the floor of the square root of the input.
+    ///
+    /// # Panics
+    /// Panics when the input is negative for signed types.
+    fn isqrt(self) -> Self;
+    /// Calculates the floor of the cube root of the input.
+    ///
+    /// # Panics
+    /// Panics when the input is negative for signed types.
+    fn icbrt(self) -> Self;
+    /// Calculates the floor of the `N`th root of the input.
+    ///
+    /// # Panics
+    /// Panics when `N` is 0, or when the input is negative for signed types.
+    ///
+    /// # Notes
+    /// The root is estimated with float approximations, then corrected by comparing the input
+    /// against the estimate and the next integer raised to the `N`th power.
+    fn iroot_const<const N: u32>(self) -> Self;
+
+    /// Calculates `self / D`, rounding towards 0.
+    ///
+    /// # Panics
+    /// Panics when `D` is 0.
+    ///
+    /// # Notes
+    /// Most platforms have no SIMD integer division instructions, so SIMD types use a
+    /// multiply-high and shifts with a multiplier calculated from `D`. For scalar types, the
+    /// compiler already does this for division by a constant.
+    fn div_const<const D: u32>(self) -> Self;
+    /// Calculates `self % D`, which has the same sign as the input for signed types.
+    ///
+    /// # Panics
+    /// Panics when `D` is 0.
+    fn rem_const<const D: u32>(self) -> Self;
+}
+
+/// Defines exact-valued functions that only apply to signed integers.
+pub trait FastExactSignedInt: FastExactInt {
+    /// Calculates `COEFF^self`, where the coefficient can be negative, so odd exponents give
+    /// negative results.
+    ///
+    /// # Panics
+    /// Panics when the exponent is negative, or when the coefficient doesn't fit in the type.
+    /// Scalar types also panic when the result overflows.
+    ///
+    /// # Notes
+    /// The power is calculated the same way as `ipow`, because there's no table of powers for a
+    /// signed coefficient.
+    fn exp_const_signed_coeff<const COEFF: i32>(self) -> Self;
+    /// Calculates `COEFF^self`, failing when the result overflows or when the exponent is
+    /// negative.
+    ///
+    /// # Panics
+    /// Panics when the coefficient doesn't fit in the type, because it's known at compile time.
+    fn checked_exp_const_signed_coeff<const COEFF: i32>(self) -> Self::Checked;
+    /// Calculates `COEFF^self`, saturating at the bounds of the type.
+    ///
+    /// # Panics
+    /// Panics when the exponent is negative, or when the coefficient doesn't fit in the type.
+    fn saturating_exp_const_signed_coeff<const COEFF: i32>(self) -> Self;
 }
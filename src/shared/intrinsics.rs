@@ -0,0 +1,311 @@
+// The intrinsics that the scalar functions use. With the `nightly` feature, these are the compiler
+// intrinsics. Without it, they're replaced with plain arithmetic and functions built from it,
+// which have the same names and signatures so that the scalar code doesn't change, and only need
+// `core`. Plain arithmetic can't be reassociated by the compiler, so the stable versions can be a
+// bit slower.
+//
+// The math functions that the checked functions fall back to are the most precise approximations
+// in this crate, so that they don't need a math library to be linked on `no_std` targets.
+//
+// The `deterministic` feature also uses plain arithmetic. Rounding and square roots are exact in
+// IEEE 754, and the stable versions calculate the same exact results with integer and plain
+// arithmetic, so those are the same on every target.
+
+#[cfg(feature = "nightly")]
+pub(crate) use core::intrinsics::{assume, nearbyintf32, nearbyintf64, sqrtf32, sqrtf64};
+#[cfg(all(feature = "nightly", not(feature = "deterministic")))]
+pub(crate) use core::intrinsics::{fadd_fast, fdiv_fast, fmul_fast, fsub_fast};
+
+#[cfg(any(not(feature = "nightly"), feature = "deterministic"))]
+pub(crate) use arithmetic::*;
+pub(crate) use math::*;
+#[cfg(not(feature = "nightly"))]
+pub(crate) use stable::*;
+
+#[cfg(any(not(feature = "nightly"), feature = "deterministic"))]
+mod arithmetic {
+    use core::ops::{Add, Div, Mul, Sub};
+
+    #[inline(always)]
+    pub(crate) unsafe fn fadd_fast<T: Add<Output = T>>(a: T, b: T) -> T {
+        a + b
+    }
+
+    #[inline(always)]
+    pub(crate) unsafe fn fsub_fast<T: Sub<Output = T>>(a: T, b: T) -> T {
+        a - b
+    }
+
+    #[inline(always)]
+    pub(crate) unsafe fn fmul_fast<T: Mul<Output = T>>(a: T, b: T) -> T {
+        a * b
+    }
+
+    #[inline(always)]
+    pub(crate) unsafe fn fdiv_fast<T: Div<Output = T>>(a: T, b: T) -> T {
+        a / b
+    }
+}
+
+#[cfg(not(feature = "nightly"))]
+mod stable {
+    #[inline(always)]
+    pub(crate) unsafe fn assume(b: bool) {
+        if !b {
+            core::hint::unreachable_unchecked();
+        }
+    }
+
+    macro_rules! float_functions {
+        ($f:ident, $u:ty, $w:ty, $nearbyint:ident, $sqrt:ident) => {
+            #[inline(always)]
+            pub(crate) unsafe fn $nearbyint(x: $f) -> $f {
+                // adding and subtracting 2^(mantissa bits) leaves no bits for the fraction, so it
+                // gets rounded away with ties to even. larger magnitudes are already integers.
+                const MIN_INTEGER: $f = (1_u64 << ($f::MANTISSA_DIGITS - 1)) as $f;
+                const SIGN_MASK: $u = 1 << (<$u>::BITS - 1);
+
+                if x > -MIN_INTEGER && x < MIN_INTEGER {
+                    let magic = if x < 0.0 { -MIN_INTEGER } else { MIN_INTEGER };
+                    let rounded = (x + magic) - magic;
+                    // negative inputs that round to 0 should give -0
+                    $f::from_bits(rounded.to_bits() | (x.to_bits() & SIGN_MASK))
+                } else {
+                    x
+                }
+            }
+
+            #[inline(always)]
+            pub(crate) unsafe fn $sqrt(x: $f) -> $f {
+                const MANT_BITS: u32 = $f::MANTISSA_DIGITS - 1;
+                const EXP_BIAS: i32 = $f::MAX_EXP - 1;
+
+                if !(x > 0.0 && x < $f::INFINITY) {
+                    // 0, infinity and NaN are returned as is, and the rest are negative
+                    return if x >= 0.0 || x.is_nan() { x } else { $f::NAN };
+                }
+
+                // split the input into an integer mantissa and a power of 2
+                let bits = x.to_bits();
+                let biased_exp = (bits >> MANT_BITS) as i32;
+                let mant_mask: $u = (1 << MANT_BITS) - 1;
+                let (mantissa, exp) = if biased_exp == 0 {
+                    (bits & mant_mask, 1 - EXP_BIAS - MANT_BITS as i32)
+                } else {
+                    (
+                        (bits & mant_mask) | (1 << MANT_BITS),
+                        biased_exp - EXP_BIAS - MANT_BITS as i32,
+                    )
+                };
+
+                // the mantissa is shifted so that its integer square root has 1 more bit than the
+                // result, and the shift is kept even so that it can be halved exactly
+                let mant_len = <$u>::BITS - mantissa.leading_zeros();
+                let mut shift = (2 * $f::MANTISSA_DIGITS + 2 - mant_len) as i32;
+                if (exp - shift) & 1 != 0 {
+                    shift -= 1;
+                }
+                let root = ((mantissa as $w) << shift).isqrt();
+
+                // the square root of a float can't be exactly halfway between 2 floats, so
+                // rounding the extra bit up gives the nearest result
+                let rounded = ((root + 1) >> 1) as $f;
+                let scale = $f::from_bits((((exp - shift) / 2 + 1 + EXP_BIAS) as $u) << MANT_BITS);
+                rounded * scale
+            }
+        };
+    }
+
+    float_functions!(f32, u32, u64, nearbyintf32, sqrtf32);
+    float_functions!(f64, u64, u128, nearbyintf64, sqrtf64);
+}
+
+mod math {
+    use super::{nearbyintf32, nearbyintf64};
+    use crate::shared::float::{double, single, P3, P7};
+
+    macro_rules! math_functions {
+        (
+            $f:ident,
+            $kernels:ident,
+            $precision:ty,
+            $nearbyint:ident,
+            $ln2_split:expr,
+            $exp:ident,
+            $exp2:ident,
+            $ln:ident,
+            $log2:ident,
+            $log10:ident,
+            $pow:ident,
+            $powi:ident
+        ) => {
+            #[inline(always)]
+            pub(crate) unsafe fn $exp2(x: $f) -> $f {
+                // results near the limits are calculated from an input that's shifted into the
+                // valid range, and then scaled back with a single multiplication, so subnormal
+                // results are rounded once
+                const SHIFT: $f = (<$f>::MANTISSA_DIGITS + 2) as $f;
+                let (min, max) = $kernels::EXP2_BOUNDS;
+
+                if x.is_nan() {
+                    x
+                } else if x > max + 1.0 {
+                    <$f>::INFINITY
+                } else if x > max {
+                    $kernels::exp2_fast_approx::<$precision>(x - 1.0) * 2.0
+                } else if x >= min {
+                    $kernels::exp2_fast_approx::<$precision>(x)
+                } else if x >= min - SHIFT {
+                    $kernels::exp2_fast_approx::<$precision>(x + SHIFT)
+                        * $powi(2.0, -(SHIFT as i32))
+                } else {
+                    0.0
+                }
+            }
+
+            #[inline(always)]
+            pub(crate) unsafe fn $exp(x: $f) -> $f {
+                const SHIFT: $f = (<$f>::MANTISSA_DIGITS + 2) as $f;
+                let (min, max) = $kernels::EXP2_BOUNDS;
+                let multiples = x * core::$f::consts::LOG2_E;
+
+                if x.is_nan() {
+                    x
+                } else if multiples > max + 2.0 {
+                    <$f>::INFINITY
+                } else if multiples < min - SHIFT - 2.0 {
+                    0.0
+                } else {
+                    // the product with log2(e) is rounded, which matters for large inputs, so the
+                    // integer part is split off first. ln(2) is split into two parts, which keeps
+                    // the remainder exact.
+                    let rounded = $nearbyint(multiples);
+                    let remainder = (x - rounded * $ln2_split.0) - rounded * $ln2_split.1;
+                    let fract_exp = $kernels::exp2_fast_approx::<$precision>(
+                        remainder * core::$f::consts::LOG2_E,
+                    );
+
+                    // the power of 2 is applied in two halves so that neither half overflows,
+                    // which leaves a single rounding for subnormal results
+                    let exponent = rounded as i32;
+                    fract_exp * $powi(2.0, exponent / 2) * $powi(2.0, exponent - exponent / 2)
+                }
+            }
+
+            #[inline(always)]
+            pub(crate) unsafe fn $log2(x: $f) -> $f {
+                if x.is_nan() || x < 0.0 {
+                    <$f>::NAN
+                } else if x == 0.0 {
+                    <$f>::NEG_INFINITY
+                } else if x.is_infinite() {
+                    x
+                } else if x < <$f>::MIN_POSITIVE {
+                    // subnormals are scaled into the normal range first
+                    const SHIFT: $f = <$f>::MANTISSA_DIGITS as $f;
+                    $kernels::log2_fast_approx::<$precision>(x * $powi(2.0, SHIFT as i32)) - SHIFT
+                } else {
+                    $kernels::log2_fast_approx::<$precision>(x)
+                }
+            }
+
+            #[inline(always)]
+            pub(crate) unsafe fn $ln(x: $f) -> $f {
+                $log2(x) * core::$f::consts::LN_2
+            }
+
+            #[inline(always)]
+            pub(crate) unsafe fn $log10(x: $f) -> $f {
+                $log2(x) * core::$f::consts::LOG10_2
+            }
+
+            #[inline(always)]
+            pub(crate) unsafe fn $pow(x: $f, exponent: $f) -> $f {
+                // halving is exact, so an integer is odd when half of it isn't an integer
+                let is_integer = exponent == $nearbyint(exponent);
+                let is_odd = is_integer && exponent * 0.5 != $nearbyint(exponent * 0.5);
+
+                if exponent == 0.0 || x == 1.0 {
+                    1.0
+                } else if x.is_nan() || exponent.is_nan() {
+                    x + exponent
+                } else if exponent.is_infinite() {
+                    let abs = if x < 0.0 { -x } else { x };
+                    if abs == 1.0 {
+                        1.0
+                    } else if (abs < 1.0) == (exponent < 0.0) {
+                        <$f>::INFINITY
+                    } else {
+                        0.0
+                    }
+                } else if x < 0.0 && x.is_finite() && !is_integer {
+                    <$f>::NAN
+                } else {
+                    // the sign of the result only depends on the sign of x and whether the
+                    // exponent is an odd integer, which also covers negative zero
+                    let abs = <$f>::from_bits(x.to_bits() << 1 >> 1);
+                    let magnitude = $exp2($log2(abs) * exponent);
+                    if is_odd && x.is_sign_negative() {
+                        -magnitude
+                    } else {
+                        magnitude
+                    }
+                }
+            }
+
+            #[inline(always)]
+            pub(crate) unsafe fn $powi(x: $f, exponent: i32) -> $f {
+                let mut remaining = exponent.unsigned_abs();
+                let mut square = x;
+                let mut result = 1.0;
+
+                while remaining != 0 {
+                    if remaining & 1 == 1 {
+                        result *= square;
+                    }
+                    remaining >>= 1;
+                    if remaining != 0 {
+                        square *= square;
+                    }
+                }
+
+                if exponent < 0 {
+                    1.0 / result
+                } else {
+                    result
+                }
+            }
+        };
+    }
+
+    // these use the most precise level of each type. ln(2) is split into a part with enough
+    // trailing zeros to be multiplied by any exponent exactly, and the rest of it.
+    math_functions!(
+        f32,
+        single,
+        P3,
+        nearbyintf32,
+        (6.931_457_5e-1, 1.428_606_8e-6),
+        expf32,
+        exp2f32,
+        logf32,
+        log2f32,
+        log10f32,
+        powf32,
+        powif32
+    );
+    math_functions!(
+        f64,
+        double,
+        P7,
+        nearbyintf64,
+        (6.931_471_803_691_238e-1, 1.908_214_929_270_587_7e-10),
+        expf64,
+        exp2f64,
+        logf64,
+        log2f64,
+        log10f64,
+        powf64,
+        powif64
+    );
+}
@@ -1,2 +1,3 @@
 pub mod float;
 pub mod int;
+pub(crate) mod intrinsics;
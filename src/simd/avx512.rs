@@ -0,0 +1,75 @@
+// Specialized paths for the vector widths that fill an AVX-512 register. These are only compiled
+// with the `avx512` feature, and only used when the target features are enabled at compile time.
+// The generic SIMD functions check the lane count and element size, and then reinterpret their
+// vectors as the intrinsic types with `cast`.
+
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use core::mem::{size_of, transmute_copy};
+
+/// Reinterprets a vector as another vector type with the same size.
+///
+/// # Safety
+/// Both types must have the same size, and every bit pattern of `A` must be valid for `B`.
+#[inline(always)]
+pub(crate) unsafe fn cast<A: Copy, B: Copy>(a: A) -> B {
+    debug_assert_eq!(size_of::<A>(), size_of::<B>());
+    transmute_copy(&a)
+}
+
+/// Splits each lane into its unbiased exponent and its mantissa in [1, 2) with `vgetexp` and
+/// `vgetmant`. Unlike reading the bits directly, this also normalizes subnormal inputs.
+#[inline(always)]
+pub(crate) fn split_exponent_ps(x: __m512) -> (__m512, __m512) {
+    unsafe {
+        (
+            _mm512_getexp_ps(x),
+            _mm512_getmant_ps::<_MM_MANT_NORM_1_2, _MM_MANT_SIGN_ZERO>(x),
+        )
+    }
+}
+
+/// Replaces the lanes of a `log2` result where the input is zero, negative, or infinite with the
+/// values that `std` returns for them, using masked moves.
+#[inline(always)]
+pub(crate) fn log2_special_cases_ps(x: __m512, result: __m512) -> __m512 {
+    unsafe {
+        let zero = _mm512_setzero_ps();
+        let infinity = _mm512_set1_ps(f32::INFINITY);
+
+        let result = _mm512_mask_mov_ps(
+            result,
+            _mm512_cmp_ps_mask::<_CMP_EQ_OQ>(x, zero),
+            _mm512_set1_ps(f32::NEG_INFINITY),
+        );
+        let result = _mm512_mask_mov_ps(
+            result,
+            _mm512_cmp_ps_mask::<_CMP_LT_OQ>(x, zero),
+            _mm512_set1_ps(f32::NAN),
+        );
+        _mm512_mask_mov_ps(
+            result,
+            _mm512_cmp_ps_mask::<_CMP_EQ_OQ>(x, infinity),
+            infinity,
+        )
+    }
+}
+
+/// Counts the leading zeros of each lane with `vplzcnt`, for vectors of `u32` or `u64` lanes
+/// that are 256 or 512 bits wide. Returns `None` for every other vector type.
+#[inline(always)]
+pub(crate) fn leading_zeros<V: Copy>(x: V, lane_bytes: usize) -> Option<V> {
+    unsafe {
+        match (size_of::<V>(), lane_bytes) {
+            (64, 4) => Some(cast(_mm512_lzcnt_epi32(cast(x)))),
+            (64, 8) => Some(cast(_mm512_lzcnt_epi64(cast(x)))),
+            #[cfg(target_feature = "avx512vl")]
+            (32, 4) => Some(cast(_mm256_lzcnt_epi32(cast(x)))),
+            #[cfg(target_feature = "avx512vl")]
+            (32, 8) => Some(cast(_mm256_lzcnt_epi64(cast(x)))),
+            _ => None,
+        }
+    }
+}
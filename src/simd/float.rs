@@ -1,62 +1,1016 @@
-use crate::shared::float::*;
-use core::array;
-use core::simd::*;
-
-impl<const LANES: usize> FastApproxFloat for Simd<f32, LANES>
-where
-    LaneCount<LANES>: SupportedLaneCount,
-{
-    #[inline(always)]
-    unsafe fn sin_fast_approx<const PRECISION: usize>(self) -> Self {
-        Simd::from_array(
-            self.to_array()
-                .map(|e| sin_fast_approx::<PRECISION, false>(e)),
-        )
-    }
-
-    #[inline(always)]
-    unsafe fn cos_fast_approx<const PRECISION: usize>(self) -> Self {
-        Simd::from_array(
-            self.to_array()
-                .map(|e| sin_fast_approx::<PRECISION, true>(e)),
-        )
-    }
-
-    #[inline(always)]
-    unsafe fn sin_restrict_fast_approx<const PRECISION: usize>(self) -> Self {
-        Simd::from_array(
-            self.to_array()
-                .map(|e| sin_restrict_fast_approx::<PRECISION>(e)),
-        )
-    }
-
-    #[inline(always)]
-    unsafe fn cos_restrict_fast_approx<const PRECISION: usize>(self) -> Self {
-        Simd::from_array(
-            self.to_array()
-                .map(|e| cos_restrict_fast_approx::<PRECISION>(e)),
-        )
-    }
-
-    #[inline(always)]
-    unsafe fn log2_fast_approx<const PRECISION: usize>(self) -> Self {
-        Simd::from_array(self.to_array().map(|e| log2_fast_approx::<PRECISION>(e)))
-    }
-
-    #[inline(always)]
-    unsafe fn log10_fast_approx<const PRECISION: usize>(self) -> Self {
-        Simd::from_array(self.to_array().map(|e| log10_fast_approx::<PRECISION>(e)))
-    }
-
-    #[inline(always)]
-    unsafe fn ln_fast_approx<const PRECISION: usize>(self) -> Self {
-        Simd::from_array(self.to_array().map(|e| ln_fast_approx::<PRECISION>(e)))
-    }
-
-    #[inline(always)]
-    unsafe fn log_fast_approx<const PRECISION: usize>(self, base: Self) -> Self {
-        Simd::from_array(array::from_fn(|i| {
-            log_fast_approx::<PRECISION>(self[i], base[i])
-        }))
-    }
+// These are the same algorithms as the scalar kernels in `shared::float`, but written with
+// `core::simd` operations so that they don't rely on auto-vectorization. `mul_add` and `round`
+// are only available in `std`, so polynomials are evaluated with separate multiplies and adds,
+// and rounding is done by adding and subtracting a magic number.
+
+macro_rules! float_impl {
+    ($f:ident,$u:ty,$s:ty,$kernels:ident,$mant_bits:expr,$log2_origin:expr) => {
+        mod $kernels {
+            use crate::shared::float::$kernels::{
+                acos_coeffs, atan_restrict_coeffs, checked_trig_max, cos_pi_fraction_coeffs,
+                cos_restrict_coeffs, exp2_coeffs, log2_coeffs, reduce_pi_multiples_payne_hanek,
+                sin_pi_fraction_coeffs, sin_restrict_coeffs, sinh_coeffs,
+                tan_half_pi_fraction_coeffs, CODY_WAITE_MAX, EXP10_BOUNDS, EXP2_BOUNDS, EXP_BOUNDS,
+                HYPERBOLIC_MAX, PI_SPLIT, RCBRT_MAGIC, RSQRT_MAGIC,
+            };
+            use crate::shared::float::{FastApproxFloat, FastApproxFloatChecked, Precision};
+            use core::array;
+            use core::simd::*;
+            use core::$f::consts::*;
+
+            const SIGN_MASK: $u = 1 << (<$u>::BITS - 1);
+            const EXP_BIAS: $s = (1 << (<$u>::BITS - 2 - $mant_bits)) - 1;
+            // the smallest multiple of 6 above the amount of mantissa bits. scaling a subnormal by
+            // 2 to the power of this makes it normal, and the square and cube roots of the scale
+            // are still powers of 2.
+            const SUBNORMAL_SHIFT: $s = ($mant_bits + 6) / 6 * 6;
+            // 2^(mantissa bits), the smallest magnitude where every value is an integer
+            const MIN_INTEGER: $f = ((1 as $u) << $mant_bits) as $f;
+            // 1.5 * 2^(mantissa bits). Adding this to a value below 2^(mantissa bits - 1) pushes
+            // the fraction out of the mantissa, leaving the rounded integer in the low bits of the
+            // sum. Unlike `round`, this is only exact for the small inputs of exp2.
+            const ROUNDING_MAGIC: $f = ((3 as $u) << ($mant_bits - 1)) as $f;
+
+            /// Evaluates a polynomial with coefficients ordered from the highest degree to the
+            /// lowest.
+            #[inline(always)]
+            fn polynomial<const LANES: usize>(x: Simd<$f, LANES>, coeffs: &[$f]) -> Simd<$f, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                let mut polynomial_eval = Simd::splat(coeffs[0]);
+                for &coeff in &coeffs[1..] {
+                    polynomial_eval = x * polynomial_eval + Simd::splat(coeff);
+                }
+                polynomial_eval
+            }
+
+            /// Calculates `2^exponent` for exponents in the normal range.
+            const fn exp2_int(exponent: $s) -> $f {
+                <$f>::from_bits(((EXP_BIAS + exponent) as $u) << $mant_bits)
+            }
+
+            /// Rounds to the nearest integer, with ties rounding to even.
+            #[inline(always)]
+            fn round<const LANES: usize>(x: Simd<$f, LANES>) -> Simd<$f, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                // adding and subtracting 2^(mantissa bits) with the sign of x pushes the fraction
+                // out of the mantissa. larger values are already integers, and are kept as-is.
+                let magic = Simd::<$f, LANES>::from_bits(
+                    (x.to_bits() & Simd::splat(SIGN_MASK)) | Simd::splat(MIN_INTEGER.to_bits()),
+                );
+                x.abs()
+                    .simd_lt(Simd::splat(MIN_INTEGER))
+                    .select((x + magic) - magic, x)
+            }
+
+            /// Checks which lanes of an integer value are odd.
+            #[inline(always)]
+            fn is_odd<const LANES: usize>(x: Simd<$f, LANES>) -> Mask<$s, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                // halving is exact, so the value is odd when half of it isn't an integer
+                let half = x * Simd::splat(0.5);
+                round(half).simd_ne(half)
+            }
+
+            /// Gives the sign bit in the lanes where the integer value is odd, which is what
+            /// results are flipped by for each multiple of PI.
+            #[inline(always)]
+            fn parity_sign<const LANES: usize>(x: Simd<$f, LANES>) -> Simd<$u, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                is_odd(x).select(Simd::splat(SIGN_MASK), Simd::splat(0))
+            }
+
+            /// There's no square root in `core::simd`, so this iterates on the reciprocal square
+            /// root until it converges for the type, which is within a few ULPs of the exact
+            /// result. This gives 0 for 0, and is valid for normal inputs otherwise.
+            #[inline(always)]
+            fn sqrt<const LANES: usize>(x: Simd<$f, LANES>) -> Simd<$f, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                // the initial guess is correct to about 5 bits, and each iteration doubles that
+                const ITERATIONS: usize = if $mant_bits > 23 { 4 } else { 3 };
+
+                let clamped = x.simd_max(Simd::splat(<$f>::MIN_POSITIVE));
+                let mut result = Simd::<$f, LANES>::from_bits(
+                    Simd::splat(RSQRT_MAGIC) - (clamped.to_bits() >> Simd::splat(1)),
+                );
+
+                let half_x = clamped * Simd::splat(0.5);
+                for _ in 0..ITERATIONS {
+                    result *= Simd::splat(1.5) - half_x * result * result;
+                }
+
+                x * result
+            }
+
+            #[inline(always)]
+            fn sin<P: Precision<$f>, const COS: bool, const LANES: usize>(
+                x: Simd<$f, LANES>,
+            ) -> Simd<$f, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                let pi_multiples =
+                    x * Simd::splat(FRAC_1_PI) + Simd::splat(if COS { 0.0 } else { -0.5 });
+                let rounded_multiples = round(pi_multiples);
+                let pi_fraction = pi_multiples - rounded_multiples;
+
+                let polynomial_eval =
+                    polynomial(pi_fraction * pi_fraction, cos_pi_fraction_coeffs::<P>());
+
+                let parity_sign = parity_sign(rounded_multiples);
+                Simd::from_bits(polynomial_eval.to_bits() ^ parity_sign)
+            }
+
+            /// Reduces each lane to a remainder between [-PI/2, PI/2] by subtracting the nearest
+            /// multiple of PI, along with the sign bit to flip the result by. Lanes above
+            /// `CODY_WAITE_MAX` use the scalar Payne-Hanek reduction, which is skipped entirely
+            /// when there aren't any.
+            #[inline(always)]
+            fn reduce_pi_multiples<const LANES: usize>(
+                x: Simd<$f, LANES>,
+            ) -> (Simd<$f, LANES>, Simd<$u, LANES>)
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                let rounded_multiples = round(x * Simd::splat(FRAC_1_PI));
+                let mut remainder = ((x - rounded_multiples * Simd::splat(PI_SPLIT[0]))
+                    - rounded_multiples * Simd::splat(PI_SPLIT[1]))
+                    - rounded_multiples * Simd::splat(PI_SPLIT[2]);
+                let mut parity_sign = parity_sign(rounded_multiples);
+
+                let cody_waite = in_range(x, -CODY_WAITE_MAX, CODY_WAITE_MAX);
+                if !cody_waite.all() {
+                    for i in 0..LANES {
+                        if !cody_waite.test(i) {
+                            (remainder[i], parity_sign[i]) = reduce_pi_multiples_payne_hanek(x[i]);
+                        }
+                    }
+                }
+
+                (remainder, parity_sign)
+            }
+
+            /// Calculates `tan(fraction * PI/2)` for fractions between [-0.5, 0.5], or
+            /// `-1/tan(...)` for fractions that are offset from a pole in the lanes where
+            /// `reciprocal` is set.
+            #[inline(always)]
+            fn tan_half_pi_fraction<P: Precision<$f>, const LANES: usize>(
+                fraction: Simd<$f, LANES>,
+                reciprocal: Mask<$s, LANES>,
+            ) -> Simd<$f, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                // 2^-(mantissa bits + 1), which keeps the reciprocal at or below
+                // 2^(mantissa bits + 1)
+                const MIN_ABS_BITS: $u = ((EXP_BIAS - 1 - $mant_bits) as $u) << $mant_bits;
+
+                let polynomial_eval =
+                    polynomial(fraction * fraction, tan_half_pi_fraction_coeffs::<P>()) * fraction;
+
+                // clamping the magnitude turns the poles into large finite values instead of
+                // infinity
+                let bits = polynomial_eval.to_bits();
+                let clamped = Simd::<$f, LANES>::from_bits(
+                    (bits & Simd::splat(!SIGN_MASK)).simd_max(Simd::splat(MIN_ABS_BITS))
+                        | (bits & Simd::splat(SIGN_MASK)),
+                );
+
+                reciprocal.select(Simd::splat(-1.0) / clamped, polynomial_eval)
+            }
+
+            /// Calculates `atan(x)` for inputs between [0, 1].
+            #[inline(always)]
+            fn atan_restrict<P: Precision<$f>, const LANES: usize>(
+                x: Simd<$f, LANES>,
+            ) -> Simd<$f, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                polynomial(x * x, atan_restrict_coeffs::<P>()) * x
+            }
+
+            impl<const LANES: usize> FastApproxFloat for Simd<$f, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                type Scalar = $f;
+
+                #[inline(always)]
+                unsafe fn sin_fast_approx<P: Precision<$f>>(self) -> Self {
+                    sin::<P, false, LANES>(self)
+                }
+
+                #[inline(always)]
+                unsafe fn cos_fast_approx<P: Precision<$f>>(self) -> Self {
+                    sin::<P, true, LANES>(self)
+                }
+
+                #[inline(always)]
+                unsafe fn sincos_fast_approx<P: Precision<$f>>(self) -> (Self, Self) {
+                    let pi_multiples = self * Simd::splat(FRAC_1_PI);
+                    let rounded_multiples = round(pi_multiples);
+                    let pi_fraction = pi_multiples - rounded_multiples;
+                    let fraction_squared = pi_fraction * pi_fraction;
+
+                    let sin_polynomial_eval =
+                        polynomial(fraction_squared, sin_pi_fraction_coeffs::<P>()) * pi_fraction;
+                    let cos_polynomial_eval =
+                        polynomial(fraction_squared, cos_pi_fraction_coeffs::<P>());
+
+                    // both sin and cos flip signs every PI
+                    let parity_sign = parity_sign(rounded_multiples);
+                    (
+                        Simd::from_bits(sin_polynomial_eval.to_bits() ^ parity_sign),
+                        Simd::from_bits(cos_polynomial_eval.to_bits() ^ parity_sign),
+                    )
+                }
+
+                #[inline(always)]
+                unsafe fn sin_wide_fast_approx<P: Precision<$f>>(self) -> Self {
+                    let (remainder, parity_sign) = reduce_pi_multiples(self);
+                    let polynomial_eval = remainder.sin_restrict_fast_approx::<P>();
+                    Self::from_bits(polynomial_eval.to_bits() ^ parity_sign)
+                }
+
+                #[inline(always)]
+                unsafe fn cos_wide_fast_approx<P: Precision<$f>>(self) -> Self {
+                    let (remainder, parity_sign) = reduce_pi_multiples(self);
+                    let polynomial_eval = remainder.cos_restrict_fast_approx::<P>();
+                    Self::from_bits(polynomial_eval.to_bits() ^ parity_sign)
+                }
+
+                #[inline(always)]
+                unsafe fn sincos_wide_fast_approx<P: Precision<$f>>(self) -> (Self, Self) {
+                    let (remainder, parity_sign) = reduce_pi_multiples(self);
+                    (
+                        Self::from_bits(
+                            remainder.sin_restrict_fast_approx::<P>().to_bits() ^ parity_sign,
+                        ),
+                        Self::from_bits(
+                            remainder.cos_restrict_fast_approx::<P>().to_bits() ^ parity_sign,
+                        ),
+                    )
+                }
+
+                #[inline(always)]
+                unsafe fn sin_restrict_fast_approx<P: Precision<$f>>(self) -> Self {
+                    polynomial(self * self, sin_restrict_coeffs::<P>()) * self
+                }
+
+                #[inline(always)]
+                unsafe fn cos_restrict_fast_approx<P: Precision<$f>>(self) -> Self {
+                    polynomial(self * self, cos_restrict_coeffs::<P>())
+                }
+
+                #[inline(always)]
+                unsafe fn tan_fast_approx<P: Precision<$f>>(self) -> Self {
+                    let half_pi_multiples = self * Simd::splat(FRAC_2_PI);
+                    let rounded_multiples = round(half_pi_multiples);
+                    let half_pi_fraction = half_pi_multiples - rounded_multiples;
+
+                    let reciprocal = is_odd(rounded_multiples);
+
+                    tan_half_pi_fraction::<P, LANES>(half_pi_fraction, reciprocal)
+                }
+
+                #[inline(always)]
+                unsafe fn tan_restrict_fast_approx<P: Precision<$f>>(self) -> Self {
+                    let half_pi_multiples = self * Simd::splat(FRAC_2_PI);
+                    let sign = half_pi_multiples.to_bits() & Simd::splat(SIGN_MASK);
+
+                    // past ±PI/4, the input is shifted by PI/2 towards 0 to use the reciprocal
+                    // identity
+                    let abs_multiples = Self::from_bits(half_pi_multiples.to_bits() ^ sign);
+                    let reciprocal = abs_multiples.simd_gt(Simd::splat(0.5));
+                    // the sign is applied after the subtraction so that a fraction of exactly 0
+                    // still keeps the sign of the pole's side
+                    let half_pi_fraction = reciprocal.select(
+                        Self::from_bits(
+                            (Simd::splat(1.0) - abs_multiples).to_bits()
+                                ^ sign
+                                ^ Simd::splat(SIGN_MASK),
+                        ),
+                        half_pi_multiples,
+                    );
+
+                    tan_half_pi_fraction::<P, LANES>(half_pi_fraction, reciprocal)
+                }
+
+                #[inline(always)]
+                unsafe fn atan_fast_approx<P: Precision<$f>>(self) -> Self {
+                    let sign = self.to_bits() & Simd::splat(SIGN_MASK);
+                    let abs = Self::from_bits(self.to_bits() ^ sign);
+
+                    // atan(x) = PI/2 - atan(1/x) for positive x
+                    let reciprocal = abs.simd_gt(Simd::splat(1.0));
+                    let reduced = reciprocal.select(Simd::splat(1.0) / abs, abs);
+
+                    let mut result = atan_restrict::<P, LANES>(reduced);
+                    result = reciprocal.select(Simd::splat(FRAC_PI_2) - result, result);
+
+                    Self::from_bits(result.to_bits() | sign)
+                }
+
+                #[inline(always)]
+                unsafe fn atan2_fast_approx<P: Precision<$f>>(self, x: Self) -> Self {
+                    let y_sign = self.to_bits() & Simd::splat(SIGN_MASK);
+                    let x_sign = x.to_bits() & Simd::splat(SIGN_MASK);
+                    let y_abs = Self::from_bits(self.to_bits() ^ y_sign);
+                    let x_abs = Self::from_bits(x.to_bits() ^ x_sign);
+
+                    // keeps the ratio between [0, 1], which is then reflected back into the first
+                    // octant
+                    let swap = y_abs.simd_gt(x_abs);
+                    let ratio = swap.select(x_abs, y_abs) / swap.select(y_abs, x_abs);
+
+                    let mut result = atan_restrict::<P, LANES>(ratio);
+                    result = swap.select(Simd::splat(FRAC_PI_2) - result, result);
+                    result = x_sign
+                        .simd_ne(Simd::splat(0))
+                        .select(Simd::splat(PI) - result, result);
+
+                    Self::from_bits(result.to_bits() | y_sign)
+                }
+
+                #[inline(always)]
+                unsafe fn asin_fast_approx<P: Precision<$f>>(self) -> Self {
+                    Simd::splat(FRAC_PI_2) - self.acos_fast_approx::<P>()
+                }
+
+                #[inline(always)]
+                unsafe fn acos_fast_approx<P: Precision<$f>>(self) -> Self {
+                    let sign = self.to_bits() & Simd::splat(SIGN_MASK);
+                    let abs = Self::from_bits(self.to_bits() ^ sign);
+
+                    // acos(x) = sqrt(1 - x) * P(x) for positive x
+                    let result = polynomial(abs, acos_coeffs::<P>()) * sqrt(Simd::splat(1.0) - abs);
+
+                    // acos(-x) = PI - acos(x)
+                    sign.simd_ne(Simd::splat(0))
+                        .select(Simd::splat(PI) - result, result)
+                }
+
+                #[inline(always)]
+                unsafe fn log2_fast_approx<P: Precision<$f>>(self) -> Self {
+                    // clears the top 2 bits, then sets the exponent to the bias, putting it in
+                    // [1, 2)
+                    const MANTISSA_MASK: $u = <$u>::MAX >> 2;
+                    const ONE_BITS: $u = (EXP_BIAS as $u) << $mant_bits;
+
+                    #[cfg(all(
+                        feature = "avx512",
+                        target_feature = "avx512f",
+                        target_feature = "avx512cd"
+                    ))]
+                    if core::mem::size_of::<$f>() == 4 && LANES == 16 {
+                        use crate::simd::avx512::{cast, split_exponent_ps};
+
+                        let (exponent, mantissa) = split_exponent_ps(cast(self));
+                        let mantissa = cast::<_, Self>(mantissa) - Simd::splat($log2_origin);
+                        return cast::<_, Self>(exponent)
+                            + polynomial(mantissa, log2_coeffs::<P>());
+                    }
+
+                    let bits = self.to_bits();
+
+                    let mantissa =
+                        Self::from_bits(bits & Simd::splat(MANTISSA_MASK) | Simd::splat(ONE_BITS))
+                            - Simd::splat($log2_origin);
+                    let mant_log2 = polynomial(mantissa, log2_coeffs::<P>());
+
+                    let exponent = ((bits >> Simd::splat($mant_bits)).cast::<$s>()
+                        - Simd::splat(EXP_BIAS))
+                    .cast::<$f>();
+
+                    exponent + mant_log2
+                }
+
+                #[inline(always)]
+                unsafe fn log10_fast_approx<P: Precision<$f>>(self) -> Self {
+                    self.log2_fast_approx::<P>() * Simd::splat(LOG10_2)
+                }
+
+                #[inline(always)]
+                unsafe fn ln_fast_approx<P: Precision<$f>>(self) -> Self {
+                    self.log2_fast_approx::<P>() * Simd::splat(LN_2)
+                }
+
+                #[inline(always)]
+                unsafe fn log_fast_approx<P: Precision<$f>>(self, base: Self) -> Self {
+                    self.log2_fast_approx::<P>() / base.log2_fast_approx::<P>()
+                }
+
+                #[inline(always)]
+                unsafe fn exp2_fast_approx<P: Precision<$f>>(self) -> Self {
+                    // splitting at the nearest integer keeps the fraction within [-0.5, 0.5]. the
+                    // valid inputs are small enough to be rounded with the magic number, which
+                    // also leaves the rounded integer in the low bits of the sum.
+                    let sum = self + Simd::splat(ROUNDING_MAGIC);
+                    let rounded = sum - Simd::splat(ROUNDING_MAGIC);
+                    let fraction = self - rounded;
+
+                    let fract_exp2 = polynomial(fraction, exp2_coeffs::<P>());
+
+                    // the integer part is added directly to the exponent bits of the fraction's
+                    // result. the bits of the magic number get shifted out.
+                    let exponent = sum.to_bits() << Simd::splat($mant_bits);
+
+                    Self::from_bits(fract_exp2.to_bits() + exponent)
+                }
+
+                #[inline(always)]
+                unsafe fn exp_fast_approx<P: Precision<$f>>(self) -> Self {
+                    (self * Simd::splat(LOG2_E)).exp2_fast_approx::<P>()
+                }
+
+                #[inline(always)]
+                unsafe fn exp10_fast_approx<P: Precision<$f>>(self) -> Self {
+                    (self * Simd::splat(LOG2_10)).exp2_fast_approx::<P>()
+                }
+
+                #[inline(always)]
+                unsafe fn pow_fast_approx<P: Precision<$f>>(self, exponent: Self) -> Self {
+                    (self.log2_fast_approx::<P>() * exponent).exp2_fast_approx::<P>()
+                }
+
+                #[inline(always)]
+                unsafe fn powi_const<const EXPONENT: i32>(self) -> Self {
+                    let mut remaining = EXPONENT.unsigned_abs();
+                    let mut square = self;
+                    let mut result = Simd::splat(1.0);
+
+                    while remaining != 0 {
+                        if remaining & 1 == 1 {
+                            result *= square;
+                        }
+                        remaining >>= 1;
+                        if remaining != 0 {
+                            square *= square;
+                        }
+                    }
+
+                    if EXPONENT < 0 {
+                        Simd::splat(1.0) / result
+                    } else {
+                        result
+                    }
+                }
+
+                #[inline(always)]
+                unsafe fn powf_const<
+                    P: Precision<$f>,
+                    const NUMERATOR: i32,
+                    const DENOMINATOR: u32,
+                >(
+                    self,
+                ) -> Self {
+                    if DENOMINATOR == 0 {
+                        panic!("invalid denominator: {:?}", DENOMINATOR);
+                    } else if DENOMINATOR == 1 {
+                        self.powi_const::<NUMERATOR>()
+                    } else {
+                        self.pow_fast_approx::<P>(Simd::splat(NUMERATOR as $f / DENOMINATOR as $f))
+                    }
+                }
+
+                #[inline(always)]
+                unsafe fn sinh_fast_approx<P: Precision<$f>>(self) -> Self {
+                    // near 0, e^x and e^-x cancel out most of the value, so a polynomial is used
+                    // there instead
+                    let exp = self.exp_fast_approx::<P>();
+                    let from_exp = (exp - Simd::splat(1.0) / exp) * Simd::splat(0.5);
+                    let from_polynomial = polynomial(self * self, sinh_coeffs::<P>()) * self;
+
+                    self.abs()
+                        .simd_lt(Simd::splat(1.0))
+                        .select(from_polynomial, from_exp)
+                }
+
+                #[inline(always)]
+                unsafe fn cosh_fast_approx<P: Precision<$f>>(self) -> Self {
+                    let exp = self.exp_fast_approx::<P>();
+                    (exp + Simd::splat(1.0) / exp) * Simd::splat(0.5)
+                }
+
+                #[inline(always)]
+                unsafe fn tanh_fast_approx<P: Precision<$f>>(self) -> Self {
+                    // tanh rounds to ±1 long before the input reaches the amount of mantissa
+                    // bits, and clamping there keeps exp within its valid range
+                    let clamped = self.simd_clamp(
+                        Simd::splat(-($mant_bits as $f)),
+                        Simd::splat($mant_bits as $f),
+                    );
+
+                    // tanh(x) = 1 - 2 / (e^2x + 1)
+                    let exp = (clamped * Simd::splat(2.0 * LOG2_E)).exp2_fast_approx::<P>();
+                    Simd::splat(1.0) - Simd::splat(2.0) / (exp + Simd::splat(1.0))
+                }
+
+                #[inline(always)]
+                unsafe fn sqrt_fast_approx<P: Precision<$f>>(self) -> Self {
+                    // the reciprocal of 0 would be infinite, so the input to it is clamped to the
+                    // smallest subnormal, and 0 is still multiplied back to 0
+                    let clamped = self
+                        .simd_gt(Simd::splat(0.0))
+                        .select(self, Simd::splat(<$f>::from_bits(1)));
+                    self * clamped.rsqrt_fast_approx::<P>()
+                }
+
+                #[inline(always)]
+                unsafe fn rsqrt_fast_approx<P: Precision<$f>>(self) -> Self {
+                    // the initial guess only works for normal inputs, so subnormals are scaled up
+                    // first, and the result is scaled by the square root of the same factor
+                    let subnormal = self.simd_lt(Simd::splat(<$f>::MIN_POSITIVE));
+                    let scaled =
+                        subnormal.select(self * Simd::splat(exp2_int(SUBNORMAL_SHIFT)), self);
+
+                    // shifting the bits right halves the exponent, and subtracting from the magic
+                    // number negates it and fixes up the bias
+                    let mut result = Self::from_bits(
+                        Simd::splat(RSQRT_MAGIC) - (scaled.to_bits() >> Simd::splat(1)),
+                    );
+
+                    let half_x = scaled * Simd::splat(0.5);
+                    for _ in 0..P::LEVEL {
+                        result *= Simd::splat(1.5) - half_x * (result * result);
+                    }
+
+                    subnormal.select(result * Simd::splat(exp2_int(SUBNORMAL_SHIFT / 2)), result)
+                }
+
+                #[inline(always)]
+                unsafe fn cbrt_fast_approx<P: Precision<$f>>(self) -> Self {
+                    // the initial guess only works for normal inputs, so subnormals are scaled up
+                    // first, and the reciprocal is scaled by the cube root of the same factor. 0
+                    // is clamped to the scaled smallest subnormal, because its reciprocal would be
+                    // infinite.
+                    let abs_bits = self.to_bits() & Simd::splat(!SIGN_MASK);
+                    let subnormal = abs_bits.simd_lt(Simd::splat(<$f>::MIN_POSITIVE.to_bits()));
+                    let scaled = subnormal.select(
+                        Self::from_bits(abs_bits.simd_max(Simd::splat(1)))
+                            * Simd::splat(exp2_int(SUBNORMAL_SHIFT)),
+                        Self::from_bits(abs_bits),
+                    );
+
+                    // the reciprocal cube root is used because its newton iteration doesn't need a
+                    // division, and multiplying it back keeps the sign and gives exactly 0 for 0
+                    let mut rcbrt = Self::from_bits(
+                        Simd::splat(RCBRT_MAGIC) - scaled.to_bits() / Simd::splat(3),
+                    );
+
+                    let third_x = scaled * Simd::splat(1.0 / 3.0);
+                    for _ in 0..P::LEVEL {
+                        let rcbrt_cubed = rcbrt * rcbrt * rcbrt;
+                        rcbrt *= Simd::splat(4.0 / 3.0) - third_x * rcbrt_cubed;
+                    }
+                    let rcbrt =
+                        subnormal.select(rcbrt * Simd::splat(exp2_int(SUBNORMAL_SHIFT / 3)), rcbrt);
+
+                    self * rcbrt * rcbrt
+                }
+            }
+
+            /// Checks which lanes are between the bounds, which is never true for NaN.
+            #[inline(always)]
+            fn in_range<const LANES: usize>(
+                x: Simd<$f, LANES>,
+                low: $f,
+                high: $f,
+            ) -> Mask<$s, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                x.simd_ge(Simd::splat(low)) & x.simd_le(Simd::splat(high))
+            }
+
+            /// Takes the fast result for valid lanes, and the result of the slow function for the
+            /// rest. The slow function is given the index of the lane, and is skipped entirely
+            /// when every lane is valid.
+            #[inline(always)]
+            fn with_fallback<const LANES: usize>(
+                valid: Mask<$s, LANES>,
+                fast: Simd<$f, LANES>,
+                slow: impl Fn(usize) -> $f,
+            ) -> Simd<$f, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                if valid.all() {
+                    fast
+                } else {
+                    Simd::from_array(array::from_fn(|i| {
+                        if valid.test(i) {
+                            fast[i]
+                        } else {
+                            slow(i)
+                        }
+                    }))
+                }
+            }
+
+            // invalid lanes are replaced with inputs that the unsafe functions are valid for, and
+            // then handled by the scalar implementation
+            impl<const LANES: usize> FastApproxFloatChecked for Simd<$f, LANES>
+            where
+                LaneCount<LANES>: SupportedLaneCount,
+            {
+                // past the checked bound, the wide functions are used for the whole vector
+                // instead of falling back to the scalar impl for each lane
+                #[inline(always)]
+                fn sin_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                    let max = checked_trig_max::<P>();
+                    let valid = in_range(self, -max, max);
+                    let fast =
+                        unsafe { valid.select(self, Simd::splat(0.0)).sin_fast_approx::<P>() };
+                    if valid.all() {
+                        fast
+                    } else {
+                        valid.select(fast, self.sin_wide_fast_approx_checked::<P>())
+                    }
+                }
+
+                #[inline(always)]
+                fn cos_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                    let max = checked_trig_max::<P>();
+                    let valid = in_range(self, -max, max);
+                    let fast =
+                        unsafe { valid.select(self, Simd::splat(0.0)).cos_fast_approx::<P>() };
+                    if valid.all() {
+                        fast
+                    } else {
+                        valid.select(fast, self.cos_wide_fast_approx_checked::<P>())
+                    }
+                }
+
+                #[inline(always)]
+                fn sincos_fast_approx_checked<P: Precision<$f>>(self) -> (Self, Self) {
+                    let max = checked_trig_max::<P>();
+                    let valid = in_range(self, -max, max);
+                    let (sin, cos) = unsafe {
+                        valid
+                            .select(self, Simd::splat(0.0))
+                            .sincos_fast_approx::<P>()
+                    };
+                    if valid.all() {
+                        (sin, cos)
+                    } else {
+                        let (wide_sin, wide_cos) = self.sincos_wide_fast_approx_checked::<P>();
+                        (valid.select(sin, wide_sin), valid.select(cos, wide_cos))
+                    }
+                }
+
+                #[inline(always)]
+                fn sin_wide_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                    let valid = self.is_finite();
+                    let fast = unsafe {
+                        valid
+                            .select(self, Simd::splat(0.0))
+                            .sin_wide_fast_approx::<P>()
+                    };
+                    valid.select(fast, Simd::splat($f::NAN))
+                }
+
+                #[inline(always)]
+                fn cos_wide_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                    let valid = self.is_finite();
+                    let fast = unsafe {
+                        valid
+                            .select(self, Simd::splat(0.0))
+                            .cos_wide_fast_approx::<P>()
+                    };
+                    valid.select(fast, Simd::splat($f::NAN))
+                }
+
+                #[inline(always)]
+                fn sincos_wide_fast_approx_checked<P: Precision<$f>>(self) -> (Self, Self) {
+                    let valid = self.is_finite();
+                    let (sin, cos) = unsafe {
+                        valid
+                            .select(self, Simd::splat(0.0))
+                            .sincos_wide_fast_approx::<P>()
+                    };
+                    (
+                        valid.select(sin, Simd::splat($f::NAN)),
+                        valid.select(cos, Simd::splat($f::NAN)),
+                    )
+                }
+
+                #[inline(always)]
+                fn sin_restrict_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                    let valid = in_range(self, -FRAC_PI_2, FRAC_PI_2);
+                    if valid.all() {
+                        unsafe { self.sin_restrict_fast_approx::<P>() }
+                    } else {
+                        self.sin_fast_approx_checked::<P>()
+                    }
+                }
+
+                #[inline(always)]
+                fn cos_restrict_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                    let valid = in_range(self, -FRAC_PI_2, FRAC_PI_2);
+                    if valid.all() {
+                        unsafe { self.cos_restrict_fast_approx::<P>() }
+                    } else {
+                        self.cos_fast_approx_checked::<P>()
+                    }
+                }
+
+                #[inline(always)]
+                fn tan_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                    let max = checked_trig_max::<P>();
+                    let valid = in_range(self, -max, max);
+                    let fast =
+                        unsafe { valid.select(self, Simd::splat(0.0)).tan_fast_approx::<P>() };
+                    if valid.all() {
+                        fast
+                    } else {
+                        let (sin, cos) = self.sincos_wide_fast_approx_checked::<P>();
+                        valid.select(fast, sin / cos)
+                    }
+                }
+
+                #[inline(always)]
+                fn tan_restrict_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                    let valid = in_range(self, -FRAC_PI_2, FRAC_PI_2);
+                    if valid.all() {
+                        unsafe { self.tan_restrict_fast_approx::<P>() }
+                    } else {
+                        self.tan_fast_approx_checked::<P>()
+                    }
+                }
+
+                #[inline(always)]
+                fn atan_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                    let valid = in_range(self, -<$f>::MAX, <$f>::MAX);
+                    let fast =
+                        unsafe { valid.select(self, Simd::splat(0.0)).atan_fast_approx::<P>() };
+                    with_fallback(valid, fast, |i| self[i].atan_fast_approx_checked::<P>())
+                }
+
+                #[inline(always)]
+                fn atan2_fast_approx_checked<P: Precision<$f>>(self, x: Self) -> Self {
+                    // the fast path doesn't handle both sides being 0 or subnormal
+                    let min_positive = Simd::splat(<$f>::MIN_POSITIVE);
+                    let valid = in_range(self, -<$f>::MAX, <$f>::MAX)
+                        & in_range(x, -<$f>::MAX, <$f>::MAX)
+                        & (self.abs().simd_ge(min_positive) | x.abs().simd_ge(min_positive));
+                    let fast = unsafe {
+                        valid
+                            .select(self, Simd::splat(1.0))
+                            .atan2_fast_approx::<P>(valid.select(x, Simd::splat(1.0)))
+                    };
+                    with_fallback(valid, fast, |i| {
+                        self[i].atan2_fast_approx_checked::<P>(x[i])
+                    })
+                }
+
+                #[inline(always)]
+                fn asin_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                    let valid = in_range(self, -1.0, 1.0);
+                    let fast =
+                        unsafe { valid.select(self, Simd::splat(0.0)).asin_fast_approx::<P>() };
+                    valid.select(fast, Simd::splat(<$f>::NAN))
+                }
+
+                #[inline(always)]
+                fn acos_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                    let valid = in_range(self, -1.0, 1.0);
+                    let fast =
+                        unsafe { valid.select(self, Simd::splat(0.0)).acos_fast_approx::<P>() };
+                    valid.select(fast, Simd::splat(<$f>::NAN))
+                }
+
+                #[inline(always)]
+                fn log2_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                    // the exponent split handles subnormal inputs, so only the special cases need
+                    // to be replaced, without a scalar fallback
+                    #[cfg(all(
+                        feature = "avx512",
+                        target_feature = "avx512f",
+                        target_feature = "avx512cd"
+                    ))]
+                    if core::mem::size_of::<$f>() == 4 && LANES == 16 {
+                        use crate::simd::avx512::{cast, log2_special_cases_ps};
+
+                        let fast = unsafe { self.log2_fast_approx::<P>() };
+                        return unsafe { cast(log2_special_cases_ps(cast(self), cast(fast))) };
+                    }
+
+                    let valid = in_range(self, <$f>::MIN_POSITIVE, <$f>::MAX);
+                    let fast =
+                        unsafe { valid.select(self, Simd::splat(1.0)).log2_fast_approx::<P>() };
+                    with_fallback(valid, fast, |i| self[i].log2_fast_approx_checked::<P>())
+                }
+
+                #[inline(always)]
+                fn log10_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                    #[cfg(all(
+                        feature = "avx512",
+                        target_feature = "avx512f",
+                        target_feature = "avx512cd"
+                    ))]
+                    if core::mem::size_of::<$f>() == 4 && LANES == 16 {
+                        return self.log2_fast_approx_checked::<P>() * Simd::splat(LOG10_2);
+                    }
+
+                    let valid = in_range(self, <$f>::MIN_POSITIVE, <$f>::MAX);
+                    let fast = unsafe {
+                        valid
+                            .select(self, Simd::splat(1.0))
+                            .log10_fast_approx::<P>()
+                    };
+                    with_fallback(valid, fast, |i| self[i].log10_fast_approx_checked::<P>())
+                }
+
+                #[inline(always)]
+                fn ln_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                    #[cfg(all(
+                        feature = "avx512",
+                        target_feature = "avx512f",
+                        target_feature = "avx512cd"
+                    ))]
+                    if core::mem::size_of::<$f>() == 4 && LANES == 16 {
+                        return self.log2_fast_approx_checked::<P>() * Simd::splat(LN_2);
+                    }
+
+                    let valid = in_range(self, <$f>::MIN_POSITIVE, <$f>::MAX);
+                    let fast =
+                        unsafe { valid.select(self, Simd::splat(1.0)).ln_fast_approx::<P>() };
+                    with_fallback(valid, fast, |i| self[i].ln_fast_approx_checked::<P>())
+                }
+
+                #[inline(always)]
+                fn log_fast_approx_checked<P: Precision<$f>>(self, base: Self) -> Self {
+                    let valid = in_range(self, <$f>::MIN_POSITIVE, <$f>::MAX)
+                        & in_range(base, <$f>::MIN_POSITIVE, <$f>::MAX);
+                    let fast = unsafe {
+                        valid
+                            .select(self, Simd::splat(1.0))
+                            .log_fast_approx::<P>(valid.select(base, Simd::splat(2.0)))
+                    };
+                    with_fallback(valid, fast, |i| {
+                        self[i].log_fast_approx_checked::<P>(base[i])
+                    })
+                }
+
+                #[inline(always)]
+                fn exp2_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                    let valid = in_range(self, EXP2_BOUNDS.0, EXP2_BOUNDS.1);
+                    let fast =
+                        unsafe { valid.select(self, Simd::splat(0.0)).exp2_fast_approx::<P>() };
+                    with_fallback(valid, fast, |i| self[i].exp2_fast_approx_checked::<P>())
+                }
+
+                #[inline(always)]
+                fn exp_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                    let valid = in_range(self, EXP_BOUNDS.0, EXP_BOUNDS.1);
+                    let fast =
+                        unsafe { valid.select(self, Simd::splat(0.0)).exp_fast_approx::<P>() };
+                    with_fallback(valid, fast, |i| self[i].exp_fast_approx_checked::<P>())
+                }
+
+                #[inline(always)]
+                fn exp10_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                    let valid = in_range(self, EXP10_BOUNDS.0, EXP10_BOUNDS.1);
+                    let fast = unsafe {
+                        valid
+                            .select(self, Simd::splat(0.0))
+                            .exp10_fast_approx::<P>()
+                    };
+                    with_fallback(valid, fast, |i| self[i].exp10_fast_approx_checked::<P>())
+                }
+
+                #[inline(always)]
+                fn pow_fast_approx_checked<P: Precision<$f>>(self, exponent: Self) -> Self {
+                    let mut valid = in_range(self, <$f>::MIN_POSITIVE, <$f>::MAX)
+                        & in_range(exponent, -<$f>::MAX, <$f>::MAX);
+                    let base = valid.select(self, Simd::splat(1.0));
+                    let exponent_valid = valid.select(exponent, Simd::splat(0.0));
+
+                    // the fast path is exp2(log2(self) * exponent), so the product has to be in
+                    // the valid range of exp2
+                    let product = unsafe { base.log2_fast_approx::<P>() } * exponent_valid;
+                    valid &= in_range(product, EXP2_BOUNDS.0, EXP2_BOUNDS.1);
+
+                    let fast = unsafe {
+                        valid
+                            .select(base, Simd::splat(1.0))
+                            .pow_fast_approx::<P>(valid.select(exponent, Simd::splat(0.0)))
+                    };
+                    with_fallback(valid, fast, |i| {
+                        self[i].pow_fast_approx_checked::<P>(exponent[i])
+                    })
+                }
+
+                #[inline(always)]
+                fn powi_const_checked<const EXPONENT: i32>(self) -> Self {
+                    if EXPONENT == 0 {
+                        return Simd::splat(1.0);
+                    }
+
+                    // the magnitude of the input is between 2^exponent and 2^(exponent + 1), so
+                    // the magnitude of the result's exponent is at most the larger of those times
+                    // EXPONENT
+                    let bound = (-EXP2_BOUNDS.0 as u64 / EXPONENT.unsigned_abs() as u64) as $s;
+                    let exponent = ((self.to_bits() << Simd::splat(1))
+                        >> Simd::splat(<$f>::MANTISSA_DIGITS as $u))
+                    .cast::<$s>()
+                        - Simd::splat(<$f>::MAX_EXP as $s - 1);
+                    let max_exponent = exponent.abs().simd_max((exponent + Simd::splat(1)).abs());
+                    let valid = self.is_normal() & max_exponent.simd_le(Simd::splat(bound));
+
+                    let fast = unsafe {
+                        valid
+                            .select(self, Simd::splat(1.0))
+                            .powi_const::<EXPONENT>()
+                    };
+                    with_fallback(valid, fast, |i| self[i].powi_const_checked::<EXPONENT>())
+                }
+
+                #[inline(always)]
+                fn powf_const_checked<
+                    P: Precision<$f>,
+                    const NUMERATOR: i32,
+                    const DENOMINATOR: u32,
+                >(
+                    self,
+                ) -> Self {
+                    if DENOMINATOR == 0 {
+                        panic!("invalid denominator: {:?}", DENOMINATOR);
+                    } else if DENOMINATOR == 1 {
+                        self.powi_const_checked::<NUMERATOR>()
+                    } else {
+                        self.pow_fast_approx_checked::<P>(Simd::splat(
+                            NUMERATOR as $f / DENOMINATOR as $f,
+                        ))
+                    }
+                }
+
+                #[inline(always)]
+                fn sinh_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                    let valid = in_range(self, -HYPERBOLIC_MAX, HYPERBOLIC_MAX);
+                    let fast =
+                        unsafe { valid.select(self, Simd::splat(0.0)).sinh_fast_approx::<P>() };
+                    with_fallback(valid, fast, |i| self[i].sinh_fast_approx_checked::<P>())
+                }
+
+                #[inline(always)]
+                fn cosh_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                    let valid = in_range(self, -HYPERBOLIC_MAX, HYPERBOLIC_MAX);
+                    let fast =
+                        unsafe { valid.select(self, Simd::splat(0.0)).cosh_fast_approx::<P>() };
+                    with_fallback(valid, fast, |i| self[i].cosh_fast_approx_checked::<P>())
+                }
+
+                #[inline(always)]
+                fn tanh_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                    let valid = in_range(self, -<$f>::MAX, <$f>::MAX);
+                    let fast =
+                        unsafe { valid.select(self, Simd::splat(0.0)).tanh_fast_approx::<P>() };
+                    with_fallback(valid, fast, |i| self[i].tanh_fast_approx_checked::<P>())
+                }
+
+                #[inline(always)]
+                fn sqrt_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                    let valid = in_range(self, 0.0, <$f>::MAX);
+                    let fast =
+                        unsafe { valid.select(self, Simd::splat(1.0)).sqrt_fast_approx::<P>() };
+                    with_fallback(valid, fast, |i| self[i].sqrt_fast_approx_checked::<P>())
+                }
+
+                #[inline(always)]
+                fn rsqrt_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                    let valid =
+                        self.simd_gt(Simd::splat(0.0)) & self.simd_le(Simd::splat(<$f>::MAX));
+                    let fast = unsafe {
+                        valid
+                            .select(self, Simd::splat(1.0))
+                            .rsqrt_fast_approx::<P>()
+                    };
+                    with_fallback(valid, fast, |i| self[i].rsqrt_fast_approx_checked::<P>())
+                }
+
+                #[inline(always)]
+                fn cbrt_fast_approx_checked<P: Precision<$f>>(self) -> Self {
+                    let valid = self.is_finite();
+                    let fast =
+                        unsafe { valid.select(self, Simd::splat(1.0)).cbrt_fast_approx::<P>() };
+                    valid.select(fast, self)
+                }
+            }
+        }
+    };
 }
+
+float_impl!(f32, u32, i32, single, 23, 0.0);
+float_impl!(f64, u64, i64, double, 52, 1.0);
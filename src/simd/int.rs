@@ -1,14 +1,53 @@
+use crate::shared::float::{FastApproxFloat, P2, P3, P4, P7};
+use crate::shared::int::consts::{div_mul_shift, ilog_mul_shift};
 use crate::shared::int::*;
-use crate::simd::int::consts::ilog_mul_shift;
 use core::mem::size_of;
+use core::ops::{Div, Rem};
 use core::simd::*;
 
+// finds the lanes where multiplying two values overflows, without needing a wider type. each value
+// is split into a high and low half, and if both high halves are nonzero, the product is at least
+// 2^BITS. otherwise, only one of the cross products can be nonzero, so the sum of the middle terms
+// fits in the type, and the product overflows when that sum does not fit in the low half.
+macro_rules! mul_overflows {
+    ($u:ty,$a:expr,$b:expr) => {{
+        let half = Simd::splat((<$u>::BITS / 2) as $u);
+        let low_mask = Simd::splat(<$u>::MAX >> (<$u>::BITS / 2));
+        let (a, b) = ($a, $b);
+        let (a_high, a_low) = (a >> half, a & low_mask);
+        let (b_high, b_low) = (b >> half, b & low_mask);
+
+        let middle = a_high * b_low + a_low * b_high + ((a_low * b_low) >> half);
+        (a_high.simd_ne(Simd::splat(0)) & b_high.simd_ne(Simd::splat(0)))
+            | (middle >> half).simd_ne(Simd::splat(0))
+    }};
+}
+
+// finds the high half of the product of two values, split into halves the same way as
+// mul_overflows. each partial product fits in the type, and the carries from the low terms are
+// added into the high term.
+macro_rules! mul_high {
+    ($u:ty,$a:expr,$b:expr) => {{
+        let half = Simd::splat((<$u>::BITS / 2) as $u);
+        let low_mask = Simd::splat(<$u>::MAX >> (<$u>::BITS / 2));
+        let (a, b) = ($a, $b);
+        let (a_high, a_low) = (a >> half, a & low_mask);
+        let (b_high, b_low) = (b >> half, b & low_mask);
+
+        let carry = a_high * b_low + ((a_low * b_low) >> half);
+        let middle = a_low * b_high + (carry & low_mask);
+        a_high * b_high + (carry >> half) + (middle >> half)
+    }};
+}
+
 macro_rules! unsigned_impl {
-    ($u:ty,$s:ty,$f:ty,$mant_bits:expr) => {
+    ($u:ty,$s:ty,$f:ty,$root_precision:ty,$log_precision:ty,$mant_bits:expr) => {
         impl<const LANES: usize> FastExactInt for Simd<$u, LANES>
         where
             LaneCount<LANES>: SupportedLaneCount,
         {
+            type Checked = (Self, Mask<$s, LANES>);
+
             #[inline(always)]
             fn ilog_const_base<const BASE: u32>(self) -> Self {
                 assert!(
@@ -25,6 +64,17 @@ macro_rules! unsigned_impl {
                 } else if BASE == 2 {
                     const UNSIGNED_LOG2: $u = (<$u>::BITS - 1) as $u;
 
+                    #[cfg(all(
+                        feature = "avx512",
+                        target_feature = "avx512f",
+                        target_feature = "avx512cd"
+                    ))]
+                    if let Some(leading_zeros) =
+                        crate::simd::avx512::leading_zeros(self, size_of::<$u>())
+                    {
+                        return Simd::splat(UNSIGNED_LOG2) - leading_zeros;
+                    }
+
                     // checks if the input is greater than the signed maximum
                     let unsigned_mask = Mask::from_int_unchecked(
                         self.cast::<$s>() >> Simd::splat(UNSIGNED_LOG2 as $s),
@@ -87,6 +137,60 @@ macro_rules! unsigned_impl {
                 }
             }
 
+            #[inline(always)]
+            fn checked_ilog_const_base<const BASE: u32>(self) -> Self::Checked {
+                let valid = self.simd_gt(Simd::splat(0));
+                // invalid lanes are replaced so the unchecked function always gets valid inputs
+                let result = unsafe {
+                    valid
+                        .select(self, Simd::splat(1))
+                        .ilog_const_base_unchecked::<BASE>()
+                };
+                (result, valid)
+            }
+
+            #[inline(always)]
+            fn ilog_var_base(self, base: Self) -> Self {
+                assert!(
+                    !self.simd_le(Simd::splat(0)).any(),
+                    "invalid input: less than 1"
+                );
+                assert!(
+                    !base.simd_le(Simd::splat(1)).any(),
+                    "invalid base: less than 2"
+                );
+                unsafe { self.ilog_var_base_unchecked(base) }
+            }
+
+            #[inline(always)]
+            unsafe fn ilog_var_base_unchecked(self, base: Self) -> Self {
+                // the log2 approximations are within 1.0e-4 of the real values. the log of the
+                // input is at most BITS, and the log of the base is at least 1, so dividing them
+                // keeps the estimate within (BITS + 1) * 1.0e-4 of the real log, well below 0.5,
+                // so subtracting 0.5 and flooring it gives either the real log or 1 less than it.
+                let estimate = self.cast::<$f>().log2_fast_approx::<P2>()
+                    / base.cast::<$f>().log2_fast_approx::<P2>();
+                let approx = (estimate - Simd::splat(0.5))
+                    .simd_max(Simd::splat(0.0))
+                    .cast::<$u>();
+
+                // calculates base^approx with a multiply for each bit that approx can have. the
+                // power can't overflow, because it's at most the input.
+                let mut power = Simd::splat(1);
+                let mut square = base;
+                for bit in 0..<$u>::BITS.ilog2() {
+                    power *= (approx & Simd::splat(1 << bit))
+                        .simd_ne(Simd::splat(0))
+                        .select(square, Simd::splat(1));
+                    square *= square;
+                }
+
+                // the next power can overflow, but if it does, it's definitely greater than the
+                // input. to_int returns 0 for false, -1 for true.
+                let next_fits = (power * base).simd_le(self) & !mul_overflows!($u, power, base);
+                (approx.cast::<$s>() - next_fits.to_int()).cast::<$u>()
+            }
+
             #[inline(always)]
             fn exp_const_coeff<const COEFF: u32>(self) -> Self {
                 assert!(
@@ -118,9 +222,548 @@ macro_rules! unsigned_impl {
                     }
                 }
             }
+
+            #[inline(always)]
+            fn checked_exp_const_coeff<const COEFF: u32>(self) -> Self::Checked {
+                let result = self.exp_const_coeff::<COEFF>();
+                let valid = if COEFF < 2 {
+                    Mask::splat(true)
+                } else {
+                    self.simd_le(Simd::splat(<$u>::MAX.ilog(COEFF as $u) as $u))
+                };
+                (result, valid)
+            }
+
+            #[inline(always)]
+            fn saturating_exp_const_coeff<const COEFF: u32>(self) -> Self {
+                let (result, valid) = self.checked_exp_const_coeff::<COEFF>();
+                valid.select(result, Simd::splat(<$u>::MAX))
+            }
+
+            #[inline(always)]
+            fn ipow(self, exp: Self) -> Self {
+                let (result, valid) = self.checked_ipow(exp);
+                assert!(valid.all(), "overflow from power");
+                result
+            }
+
+            #[inline(always)]
+            fn wrapping_ipow(self, exp: Self) -> Self {
+                // like exp_const_coeff, but the power at each bit is squared from the last one,
+                // and the loop stops once no lane has bits left
+                let mut result = Simd::splat(1);
+                let mut square = self;
+                let mut remaining = exp;
+                while remaining.simd_ne(Simd::splat(0)).any() {
+                    result *= (remaining & Simd::splat(1))
+                        .simd_eq(Simd::splat(1))
+                        .select(square, Simd::splat(1));
+                    remaining >>= Simd::splat(1);
+                    square *= square;
+                }
+
+                result
+            }
+
+            #[inline(always)]
+            fn checked_ipow(self, exp: Self) -> Self::Checked {
+                let mut result = Simd::splat(1);
+                let mut square = self;
+                let mut remaining = exp;
+                let mut overflow = Mask::splat(false);
+                while remaining.simd_ne(Simd::splat(0)).any() {
+                    let factor = (remaining & Simd::splat(1))
+                        .simd_eq(Simd::splat(1))
+                        .select(square, Simd::splat(1));
+                    overflow |= mul_overflows!($u, result, factor);
+                    result *= factor;
+                    remaining >>= Simd::splat(1);
+                    // the square only matters if there are bits left to multiply it into
+                    overflow |=
+                        remaining.simd_ne(Simd::splat(0)) & mul_overflows!($u, square, square);
+                    square *= square;
+                }
+
+                (result, !overflow)
+            }
+
+            #[inline(always)]
+            fn saturating_ipow(self, exp: Self) -> Self {
+                let (result, valid) = self.checked_ipow(exp);
+                valid.select(result, Simd::splat(<$u>::MAX))
+            }
+
+            #[inline(always)]
+            fn isqrt(self) -> Self {
+                self.iroot_const::<2>()
+            }
+
+            #[inline(always)]
+            fn icbrt(self) -> Self {
+                self.iroot_const::<3>()
+            }
+
+            #[inline(always)]
+            fn iroot_const<const N: u32>(self) -> Self {
+                if N == 0 {
+                    panic!("invalid root: {:?}", N);
+                } else if N == 1 {
+                    return self;
+                }
+
+                let x = self.cast::<$f>();
+                let estimate = unsafe {
+                    match N {
+                        2 => x.sqrt_fast_approx::<$root_precision>(),
+                        3 => x.cbrt_fast_approx::<$root_precision>(),
+                        _ => (x.log2_fast_approx::<$log_precision>() / Simd::splat(N as $f))
+                            .exp2_fast_approx::<$log_precision>(),
+                    }
+                };
+                // the log of 0 isn't valid, and the roots of 0 and 1 are themselves
+                let root = self
+                    .simd_lt(Simd::splat(2))
+                    .select(self, estimate.cast::<$u>());
+
+                // the estimate is either correct or off by 1. to_int returns 0 for false, -1 for
+                // true
+                let exponent = Simd::splat(N as $u);
+                let (power, valid) = root.checked_ipow(exponent);
+                let root =
+                    (root.cast::<$s>() + (!valid | power.simd_gt(self)).to_int()).cast::<$u>();
+                let (power, valid) = (root + Simd::splat(1)).checked_ipow(exponent);
+                (root.cast::<$s>() - (valid & power.simd_le(self)).to_int()).cast::<$u>()
+            }
+
+            #[inline(always)]
+            fn div_const<const D: u32>(self) -> Self {
+                if D == 0 {
+                    panic!("invalid divisor: {:?}", D);
+                } else if D == 1 {
+                    self
+                } else if D.is_power_of_two() {
+                    self >> Simd::splat(D.ilog2() as $u)
+                } else {
+                    let mul_shift = div_mul_shift!($u, D);
+
+                    let high = mul_high!($u, self, Simd::splat(mul_shift.0));
+                    (high + ((self - high) >> Simd::splat(1))) >> Simd::splat(mul_shift.1 - 1)
+                }
+            }
+
+            #[inline(always)]
+            fn rem_const<const D: u32>(self) -> Self {
+                self - self.div_const::<D>() * Simd::splat(D as $u)
+            }
+        }
+    };
+}
+
+// the last two parameters are the precision levels for the root and log estimates in iroot_const
+unsigned_impl!(u32, i32, f32, P3, P3, 23);
+unsigned_impl!(u64, i64, f64, P4, P7, 52);
+
+// these types don't have their own impls, and are instead cast to a type that does
+macro_rules! cast_impl {
+    ($u:ty,$s:ty,$via:ty) => {
+        impl<const LANES: usize> FastExactInt for Simd<$u, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            type Checked = (Self, Mask<$s, LANES>);
+
+            #[inline(always)]
+            fn ilog_const_base<const BASE: u32>(self) -> Self {
+                assert!(
+                    !self.simd_le(Simd::splat(0)).any(),
+                    "invalid input: less than 1"
+                );
+                unsafe { self.ilog_const_base_unchecked::<BASE>() }
+            }
+
+            #[inline(always)]
+            unsafe fn ilog_const_base_unchecked<const BASE: u32>(self) -> Self {
+                if BASE as $via > <$u>::MAX as $via {
+                    panic!("invalid base: {:?}", BASE);
+                }
+
+                self.cast::<$via>()
+                    .ilog_const_base_unchecked::<BASE>()
+                    .cast::<$u>()
+            }
+
+            #[inline(always)]
+            fn checked_ilog_const_base<const BASE: u32>(self) -> Self::Checked {
+                if BASE as $via > <$u>::MAX as $via {
+                    panic!("invalid base: {:?}", BASE);
+                }
+
+                let (result, valid) = self.cast::<$via>().checked_ilog_const_base::<BASE>();
+                (result.cast::<$u>(), valid.cast::<$s>())
+            }
+
+            #[inline(always)]
+            fn ilog_var_base(self, base: Self) -> Self {
+                assert!(
+                    !self.simd_le(Simd::splat(0)).any(),
+                    "invalid input: less than 1"
+                );
+                assert!(
+                    !base.simd_le(Simd::splat(1)).any(),
+                    "invalid base: less than 2"
+                );
+                unsafe { self.ilog_var_base_unchecked(base) }
+            }
+
+            #[inline(always)]
+            unsafe fn ilog_var_base_unchecked(self, base: Self) -> Self {
+                self.cast::<$via>()
+                    .ilog_var_base_unchecked(base.cast::<$via>())
+                    .cast::<$u>()
+            }
+
+            #[inline(always)]
+            fn exp_const_coeff<const COEFF: u32>(self) -> Self {
+                assert!(
+                    COEFF as $via <= <$u>::MAX as $via,
+                    "invalid coefficient: {:?}",
+                    COEFF
+                );
+
+                self.cast::<$via>().exp_const_coeff::<COEFF>().cast::<$u>()
+            }
+
+            #[inline(always)]
+            fn checked_exp_const_coeff<const COEFF: u32>(self) -> Self::Checked {
+                assert!(
+                    COEFF as $via <= <$u>::MAX as $via,
+                    "invalid coefficient: {:?}",
+                    COEFF
+                );
+
+                let (result, valid) = self.cast::<$via>().checked_exp_const_coeff::<COEFF>();
+                let valid = valid & result.simd_le(Simd::splat(<$u>::MAX as $via));
+                (result.cast::<$u>(), valid.cast::<$s>())
+            }
+
+            #[inline(always)]
+            fn saturating_exp_const_coeff<const COEFF: u32>(self) -> Self {
+                let (result, valid) = self.checked_exp_const_coeff::<COEFF>();
+                valid.select(result, Simd::splat(<$u>::MAX))
+            }
+
+            #[inline(always)]
+            fn ipow(self, exp: Self) -> Self {
+                let (result, valid) = self.checked_ipow(exp);
+                assert!(valid.all(), "overflow from power");
+                result
+            }
+
+            #[inline(always)]
+            fn wrapping_ipow(self, exp: Self) -> Self {
+                // the low bits of a wrapping product don't depend on the width of the type
+                self.cast::<$via>()
+                    .wrapping_ipow(exp.cast::<$via>())
+                    .cast::<$u>()
+            }
+
+            #[inline(always)]
+            fn checked_ipow(self, exp: Self) -> Self::Checked {
+                let (result, valid) = self.cast::<$via>().checked_ipow(exp.cast::<$via>());
+                let valid = valid & result.simd_le(Simd::splat(<$u>::MAX as $via));
+                (result.cast::<$u>(), valid.cast::<$s>())
+            }
+
+            #[inline(always)]
+            fn saturating_ipow(self, exp: Self) -> Self {
+                let (result, valid) = self.checked_ipow(exp);
+                valid.select(result, Simd::splat(<$u>::MAX))
+            }
+
+            #[inline(always)]
+            fn isqrt(self) -> Self {
+                self.iroot_const::<2>()
+            }
+
+            #[inline(always)]
+            fn icbrt(self) -> Self {
+                self.iroot_const::<3>()
+            }
+
+            #[inline(always)]
+            fn iroot_const<const N: u32>(self) -> Self {
+                self.cast::<$via>().iroot_const::<N>().cast::<$u>()
+            }
+
+            #[inline(always)]
+            fn div_const<const D: u32>(self) -> Self {
+                self.cast::<$via>().div_const::<D>().cast::<$u>()
+            }
+
+            #[inline(always)]
+            fn rem_const<const D: u32>(self) -> Self {
+                self.cast::<$via>().rem_const::<D>().cast::<$u>()
+            }
+        }
+    };
+}
+
+cast_impl!(u8, i8, u32);
+cast_impl!(u16, i16, u32);
+#[cfg(target_pointer_width = "32")]
+cast_impl!(usize, isize, u32);
+#[cfg(target_pointer_width = "64")]
+cast_impl!(usize, isize, u64);
+
+macro_rules! signed_impl {
+    ($s:ty,$u:ty) => {
+        impl<const LANES: usize> FastExactInt for Simd<$s, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            type Checked = (Self, Mask<$s, LANES>);
+
+            #[inline(always)]
+            fn ilog_const_base<const BASE: u32>(self) -> Self {
+                assert!(
+                    !self.simd_le(Simd::splat(0)).any(),
+                    "invalid input: less than 1"
+                );
+                unsafe { self.ilog_const_base_unchecked::<BASE>() }
+            }
+
+            #[inline(always)]
+            unsafe fn ilog_const_base_unchecked<const BASE: u32>(self) -> Self {
+                // positive signed values have the same representation as unsigned ones
+                self.cast::<$u>()
+                    .ilog_const_base_unchecked::<BASE>()
+                    .cast::<$s>()
+            }
+
+            #[inline(always)]
+            fn checked_ilog_const_base<const BASE: u32>(self) -> Self::Checked {
+                let valid = self.simd_gt(Simd::splat(0));
+                // invalid lanes are replaced so the unchecked function always gets valid inputs
+                let result = unsafe {
+                    valid
+                        .select(self, Simd::splat(1))
+                        .ilog_const_base_unchecked::<BASE>()
+                };
+                (result, valid)
+            }
+
+            #[inline(always)]
+            fn ilog_var_base(self, base: Self) -> Self {
+                assert!(
+                    !self.simd_le(Simd::splat(0)).any(),
+                    "invalid input: less than 1"
+                );
+                assert!(
+                    !base.simd_le(Simd::splat(1)).any(),
+                    "invalid base: less than 2"
+                );
+                unsafe { self.ilog_var_base_unchecked(base) }
+            }
+
+            #[inline(always)]
+            unsafe fn ilog_var_base_unchecked(self, base: Self) -> Self {
+                self.cast::<$u>()
+                    .ilog_var_base_unchecked(base.cast::<$u>())
+                    .cast::<$s>()
+            }
+
+            #[inline(always)]
+            fn exp_const_coeff<const COEFF: u32>(self) -> Self {
+                assert!(
+                    !self.simd_lt(Simd::splat(0)).any(),
+                    "invalid exponent: less than 0"
+                );
+                self.cast::<$u>().exp_const_coeff::<COEFF>().cast::<$s>()
+            }
+
+            #[inline(always)]
+            fn checked_exp_const_coeff<const COEFF: u32>(self) -> Self::Checked {
+                let (result, valid) = self.cast::<$u>().checked_exp_const_coeff::<COEFF>();
+                let valid = valid
+                    & result.simd_le(Simd::splat(<$s>::MAX as $u))
+                    & self.simd_ge(Simd::splat(0));
+                (result.cast::<$s>(), valid)
+            }
+
+            #[inline(always)]
+            fn saturating_exp_const_coeff<const COEFF: u32>(self) -> Self {
+                assert!(
+                    !self.simd_lt(Simd::splat(0)).any(),
+                    "invalid exponent: less than 0"
+                );
+                let (result, valid) = self.checked_exp_const_coeff::<COEFF>();
+                valid.select(result, Simd::splat(<$s>::MAX))
+            }
+
+            #[inline(always)]
+            fn ipow(self, exp: Self) -> Self {
+                assert!(
+                    !exp.simd_lt(Simd::splat(0)).any(),
+                    "invalid exponent: less than 0"
+                );
+                let (result, valid) = self.checked_ipow(exp);
+                assert!(valid.all(), "overflow from power");
+                result
+            }
+
+            #[inline(always)]
+            fn wrapping_ipow(self, exp: Self) -> Self {
+                assert!(
+                    !exp.simd_lt(Simd::splat(0)).any(),
+                    "invalid exponent: less than 0"
+                );
+                // wrapping multiplication is the same for signed and unsigned values
+                self.cast::<$u>()
+                    .wrapping_ipow(exp.cast::<$u>())
+                    .cast::<$s>()
+            }
+
+            #[inline(always)]
+            fn checked_ipow(self, exp: Self) -> Self::Checked {
+                // abs wraps the minimum to itself, which is still the right magnitude as unsigned
+                let (magnitude, valid) = self.abs().cast::<$u>().checked_ipow(exp.cast::<$u>());
+                let negative =
+                    self.simd_lt(Simd::splat(0)) & (exp & Simd::splat(1)).simd_eq(Simd::splat(1));
+                // the negative range has room for one more value than the positive range
+                let limit = negative.select(
+                    Simd::splat(<$s>::MIN.unsigned_abs()),
+                    Simd::splat(<$s>::MAX as $u),
+                );
+                let valid = valid & magnitude.simd_le(limit) & exp.simd_ge(Simd::splat(0));
+
+                let result = magnitude.cast::<$s>();
+                (negative.select(-result, result), valid)
+            }
+
+            #[inline(always)]
+            fn saturating_ipow(self, exp: Self) -> Self {
+                assert!(
+                    !exp.simd_lt(Simd::splat(0)).any(),
+                    "invalid exponent: less than 0"
+                );
+                let (result, valid) = self.checked_ipow(exp);
+                let negative =
+                    self.simd_lt(Simd::splat(0)) & (exp & Simd::splat(1)).simd_eq(Simd::splat(1));
+                valid.select(
+                    result,
+                    negative.select(Simd::splat(<$s>::MIN), Simd::splat(<$s>::MAX)),
+                )
+            }
+
+            #[inline(always)]
+            fn isqrt(self) -> Self {
+                self.iroot_const::<2>()
+            }
+
+            #[inline(always)]
+            fn icbrt(self) -> Self {
+                self.iroot_const::<3>()
+            }
+
+            #[inline(always)]
+            fn iroot_const<const N: u32>(self) -> Self {
+                assert!(
+                    !self.simd_lt(Simd::splat(0)).any(),
+                    "invalid input: less than 0"
+                );
+                self.cast::<$u>().iroot_const::<N>().cast::<$s>()
+            }
+
+            #[inline(always)]
+            fn div_const<const D: u32>(self) -> Self {
+                // abs wraps the minimum to itself, which is still the right magnitude as unsigned
+                let quotient = self.abs().cast::<$u>().div_const::<D>().cast::<$s>();
+                self.simd_lt(Simd::splat(0)).select(-quotient, quotient)
+            }
+
+            #[inline(always)]
+            fn rem_const<const D: u32>(self) -> Self {
+                let remainder = self.abs().cast::<$u>().rem_const::<D>().cast::<$s>();
+                self.simd_lt(Simd::splat(0)).select(-remainder, remainder)
+            }
+        }
+
+        impl<const LANES: usize> FastExactSignedInt for Simd<$s, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            #[inline(always)]
+            fn exp_const_signed_coeff<const COEFF: i32>(self) -> Self {
+                assert!(
+                    <$s>::try_from(COEFF).is_ok(),
+                    "invalid coefficient: {:?}",
+                    COEFF
+                );
+                // like exp_const_coeff, overflowing lanes wrap instead of panicking
+                Simd::splat(COEFF as $s).wrapping_ipow(self)
+            }
+
+            #[inline(always)]
+            fn checked_exp_const_signed_coeff<const COEFF: i32>(self) -> Self::Checked {
+                assert!(
+                    <$s>::try_from(COEFF).is_ok(),
+                    "invalid coefficient: {:?}",
+                    COEFF
+                );
+                Simd::splat(COEFF as $s).checked_ipow(self)
+            }
+
+            #[inline(always)]
+            fn saturating_exp_const_signed_coeff<const COEFF: i32>(self) -> Self {
+                assert!(
+                    <$s>::try_from(COEFF).is_ok(),
+                    "invalid coefficient: {:?}",
+                    COEFF
+                );
+                Simd::splat(COEFF as $s).saturating_ipow(self)
+            }
+        }
+    };
+}
+
+signed_impl!(i8, u8);
+signed_impl!(i16, u16);
+signed_impl!(i32, u32);
+signed_impl!(i64, u64);
+signed_impl!(isize, usize);
+
+macro_rules! divisor_impl {
+    ($u:ty) => {
+        impl<const LANES: usize> Div<FastDivisor<$u>> for Simd<$u, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            type Output = Self;
+
+            #[inline(always)]
+            fn div(self, rhs: FastDivisor<$u>) -> Self {
+                let high = mul_high!($u, self, Simd::splat(rhs.multiplier));
+                (high + ((self - high) >> Simd::splat(rhs.pre_shift)))
+                    >> Simd::splat(rhs.post_shift)
+            }
+        }
+
+        impl<const LANES: usize> Rem<FastDivisor<$u>> for Simd<$u, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            type Output = Self;
+
+            #[inline(always)]
+            fn rem(self, rhs: FastDivisor<$u>) -> Self {
+                self - (self / rhs) * Simd::splat(rhs.divisor)
+            }
         }
     };
 }
 
-unsigned_impl!(u32, i32, f32, 23);
-unsigned_impl!(u64, i64, f64, 52);
+divisor_impl!(u8);
+divisor_impl!(u16);
+divisor_impl!(u32);
+divisor_impl!(u64);
+divisor_impl!(usize);
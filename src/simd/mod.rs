@@ -1,2 +1,8 @@
+#[cfg(all(
+    feature = "avx512",
+    target_feature = "avx512f",
+    target_feature = "avx512cd"
+))]
+mod avx512;
 pub mod float;
 pub mod int;
@@ -1,5 +1,6 @@
 use crate::shared::float::*;
 use crate::shared::int::*;
+use core::array;
 use core::f32::consts::FRAC_PI_2;
 use core::ops::Range;
 use core::simd::*;
@@ -21,10 +22,10 @@ pub fn scalar_error() {
     for _i in 0..ITERS {
         let x = rng.gen_range(RANGE);
 
-        let approx_0 = unsafe { x.sin_fast_approx::<0>() };
-        let approx_1 = unsafe { x.sin_fast_approx::<1>() };
-        let approx_2 = unsafe { x.sin_fast_approx::<2>() };
-        let approx_3 = unsafe { x.sin_fast_approx::<3>() };
+        let approx_0 = unsafe { x.sin_fast_approx::<P0>() };
+        let approx_1 = unsafe { x.sin_fast_approx::<P1>() };
+        let approx_2 = unsafe { x.sin_fast_approx::<P2>() };
+        let approx_3 = unsafe { x.sin_fast_approx::<P3>() };
 
         let exact = x.sin();
 
@@ -71,10 +72,10 @@ pub fn simd_error() {
         for _i in 0..ITERS {
             let x = Simd::from_array([0;LANES].map(|_| rng.gen_range(RANGE)));
 
-            let approx_0 = unsafe { x.sin_fast_approx::<0>() };
-            let approx_1 = unsafe { x.sin_fast_approx::<1>() };
-            let approx_2 = unsafe { x.sin_fast_approx::<2>() };
-            let approx_3 = unsafe { x.sin_fast_approx::<3>() };
+            let approx_0 = unsafe { x.sin_fast_approx::<P0>() };
+            let approx_1 = unsafe { x.sin_fast_approx::<P1>() };
+            let approx_2 = unsafe { x.sin_fast_approx::<P2>() };
+            let approx_3 = unsafe { x.sin_fast_approx::<P3>() };
 
             let exact = Simd::from_array(x.to_array().map(|x| x.sin()));
 
@@ -122,6 +123,747 @@ pub fn simd_error() {
     }
 }
 
+#[inline(never)]
+#[test]
+pub fn sincos_error() {
+    const RANGE: Range<f32> = -FRAC_PI_2..FRAC_PI_2;
+    const MAX_ERROR_0: f32 = 2.9e-2_f32;
+    const MAX_ERROR_1: f32 = 6.0e-4_f32;
+    const MAX_ERROR_2: f32 = 7.2e-6_f32;
+    const MAX_ERROR_3: f32 = 3.0e-7_f32;
+
+    let mut rng = thread_rng();
+    for _i in 0..ITERS {
+        let x = rng.gen_range(RANGE);
+
+        let (sin_approx_0, cos_approx_0) = unsafe { x.sincos_fast_approx::<P0>() };
+        let (sin_approx_1, cos_approx_1) = unsafe { x.sincos_fast_approx::<P1>() };
+        let (sin_approx_2, cos_approx_2) = unsafe { x.sincos_fast_approx::<P2>() };
+        let (sin_approx_3, cos_approx_3) = unsafe { x.sincos_fast_approx::<P3>() };
+
+        let (sin_exact, cos_exact) = x.sin_cos();
+
+        for (exact, approx, max_error) in [
+            (sin_exact, sin_approx_0, MAX_ERROR_0),
+            (cos_exact, cos_approx_0, MAX_ERROR_0),
+            (sin_exact, sin_approx_1, MAX_ERROR_1),
+            (cos_exact, cos_approx_1, MAX_ERROR_1),
+            (sin_exact, sin_approx_2, MAX_ERROR_2),
+            (cos_exact, cos_approx_2, MAX_ERROR_2),
+            (sin_exact, sin_approx_3, MAX_ERROR_3),
+            (cos_exact, cos_approx_3, MAX_ERROR_3),
+        ] {
+            assert!(
+                (exact - approx).abs() <= max_error,
+                "Error greater than set maximum: true: {exact}, approx: {approx}, x: {x}"
+            );
+        }
+    }
+}
+
+#[inline(never)]
+#[test]
+pub fn tan_error() {
+    // stays away from the poles, where the error of the range reduction dominates
+    const RANGE: Range<f32> = -1.5..1.5;
+    const MAX_ERROR_0: f32 = 6.2e-4_f32;
+    const MAX_ERROR_1: f32 = 4.6e-5_f32;
+    const MAX_ERROR_2: f32 = 5.0e-6_f32;
+    const MAX_ERROR_3: f32 = 2.5e-6_f32;
+
+    let mut rng = thread_rng();
+    for _i in 0..ITERS {
+        let x = rng.gen_range(RANGE);
+
+        let approx_0 = unsafe { x.tan_fast_approx::<P0>() };
+        let approx_1 = unsafe { x.tan_fast_approx::<P1>() };
+        let approx_2 = unsafe { x.tan_fast_approx::<P2>() };
+        let approx_3 = unsafe { x.tan_fast_approx::<P3>() };
+
+        let exact = x.tan();
+        // keeps the relative error from dividing by 0 when x is exactly 0
+        let scale = exact.abs().max(f32::MIN_POSITIVE);
+
+        // relative error, because the output is unbounded
+        assert!(
+            ((exact - approx_0) / scale).abs() <= MAX_ERROR_0,
+            "Error greater than set maximum: true: {exact}, approx: {approx_0}, x: {x}"
+        );
+        assert!(
+            ((exact - approx_1) / scale).abs() <= MAX_ERROR_1,
+            "Error greater than set maximum: true: {exact}, approx: {approx_1}, x: {x}"
+        );
+        assert!(
+            ((exact - approx_2) / scale).abs() <= MAX_ERROR_2,
+            "Error greater than set maximum: true: {exact}, approx: {approx_2}, x: {x}"
+        );
+        assert!(
+            ((exact - approx_3) / scale).abs() <= MAX_ERROR_3,
+            "Error greater than set maximum: true: {exact}, approx: {approx_3}, x: {x}"
+        );
+    }
+
+    let pos_pole = unsafe { FRAC_PI_2.tan_restrict_fast_approx::<P3>() };
+    let neg_pole = unsafe { (-FRAC_PI_2).tan_restrict_fast_approx::<P3>() };
+    assert!(
+        pos_pole.is_finite() && pos_pole >= 1.0e7_f32,
+        "Pole not saturated: approx: {pos_pole}"
+    );
+    assert!(
+        neg_pole.is_finite() && neg_pole <= -1.0e7_f32,
+        "Pole not saturated: approx: {neg_pole}"
+    );
+}
+
+#[inline(never)]
+#[test]
+pub fn inverse_trig_error() {
+    const RANGE: Range<f32> = -1.0..1.0;
+    const ATAN_MAX_ERROR_0: f32 = 8.3e-5_f32;
+    const ATAN_MAX_ERROR_1: f32 = 1.3e-5_f32;
+    const ATAN_MAX_ERROR_2: f32 = 2.1e-6_f32;
+    const ATAN_MAX_ERROR_3: f32 = 6.5e-7_f32;
+    const ACOS_MAX_ERROR_0: f32 = 4.0e-5_f32;
+    const ACOS_MAX_ERROR_3: f32 = 5.5e-7_f32;
+
+    let mut rng = thread_rng();
+    for _i in 0..ITERS {
+        let y = rng.gen_range(RANGE);
+        let x = rng.gen_range(RANGE);
+
+        let approx_0 = unsafe { y.atan2_fast_approx::<P0>(x) };
+        let approx_1 = unsafe { y.atan2_fast_approx::<P1>(x) };
+        let approx_2 = unsafe { y.atan2_fast_approx::<P2>(x) };
+        let approx_3 = unsafe { y.atan2_fast_approx::<P3>(x) };
+
+        let exact = y.atan2(x);
+
+        assert!(
+            (exact - approx_0).abs() <= ATAN_MAX_ERROR_0,
+            "Error greater than set maximum: true: {exact}, approx: {approx_0}, y: {y}, x: {x}"
+        );
+        assert!(
+            (exact - approx_1).abs() <= ATAN_MAX_ERROR_1,
+            "Error greater than set maximum: true: {exact}, approx: {approx_1}, y: {y}, x: {x}"
+        );
+        assert!(
+            (exact - approx_2).abs() <= ATAN_MAX_ERROR_2,
+            "Error greater than set maximum: true: {exact}, approx: {approx_2}, y: {y}, x: {x}"
+        );
+        assert!(
+            (exact - approx_3).abs() <= ATAN_MAX_ERROR_3,
+            "Error greater than set maximum: true: {exact}, approx: {approx_3}, y: {y}, x: {x}"
+        );
+
+        let acos_approx_0 = unsafe { x.acos_fast_approx::<P0>() };
+        let acos_approx_3 = unsafe { x.acos_fast_approx::<P3>() };
+        let asin_approx_3 = unsafe { x.asin_fast_approx::<P3>() };
+
+        let acos_exact = x.acos();
+        let asin_exact = x.asin();
+
+        assert!(
+            (acos_exact - acos_approx_0).abs() <= ACOS_MAX_ERROR_0,
+            "Error greater than set maximum: true: {acos_exact}, approx: {acos_approx_0}, x: {x}"
+        );
+        assert!(
+            (acos_exact - acos_approx_3).abs() <= ACOS_MAX_ERROR_3,
+            "Error greater than set maximum: true: {acos_exact}, approx: {acos_approx_3}, x: {x}"
+        );
+        assert!(
+            (asin_exact - asin_approx_3).abs() <= ACOS_MAX_ERROR_3,
+            "Error greater than set maximum: true: {asin_exact}, approx: {asin_approx_3}, x: {x}"
+        );
+    }
+}
+
+#[inline(never)]
+#[test]
+pub fn exp2_error() {
+    const RANGE: Range<f32> = -125.0..127.0;
+    const MAX_ERROR_0: f32 = 1.8e-3_f32;
+    const MAX_ERROR_1: f32 = 7.7e-5_f32;
+    const MAX_ERROR_2: f32 = 2.8e-6_f32;
+    const MAX_ERROR_3: f32 = 2.5e-7_f32;
+
+    let mut rng = thread_rng();
+    for _i in 0..ITERS {
+        let x = rng.gen_range(RANGE);
+
+        let approx_0 = unsafe { x.exp2_fast_approx::<P0>() };
+        let approx_1 = unsafe { x.exp2_fast_approx::<P1>() };
+        let approx_2 = unsafe { x.exp2_fast_approx::<P2>() };
+        let approx_3 = unsafe { x.exp2_fast_approx::<P3>() };
+
+        let exact = x.exp2();
+
+        // relative error, because the output spans the entire exponent range
+        assert!(
+            ((exact - approx_0) / exact).abs() <= MAX_ERROR_0,
+            "Error greater than set maximum: true: {exact}, approx: {approx_0}, x: {x}"
+        );
+        assert!(
+            ((exact - approx_1) / exact).abs() <= MAX_ERROR_1,
+            "Error greater than set maximum: true: {exact}, approx: {approx_1}, x: {x}"
+        );
+        assert!(
+            ((exact - approx_2) / exact).abs() <= MAX_ERROR_2,
+            "Error greater than set maximum: true: {exact}, approx: {approx_2}, x: {x}"
+        );
+        assert!(
+            ((exact - approx_3) / exact).abs() <= MAX_ERROR_3,
+            "Error greater than set maximum: true: {exact}, approx: {approx_3}, x: {x}"
+        );
+    }
+}
+
+#[inline(never)]
+#[test]
+pub fn f64_error() {
+    const SIN_RANGE: Range<f64> = -core::f64::consts::FRAC_PI_2..core::f64::consts::FRAC_PI_2;
+    const EXP2_RANGE: Range<f64> = -1021.0..1023.0;
+    const MAX_SIN_ERROR_3: f64 = 4.7e-8_f64;
+    const MAX_SIN_ERROR_7: f64 = 6.0e-16_f64;
+    const MAX_EXP2_ERROR_3: f64 = 7.6e-8_f64;
+    const MAX_EXP2_ERROR_7: f64 = 4.5e-16_f64;
+
+    let mut rng = thread_rng();
+    for _i in 0..ITERS {
+        let x = rng.gen_range(SIN_RANGE);
+
+        let approx_3 = unsafe { x.sin_fast_approx::<P3>() };
+        let approx_7 = unsafe { x.sin_fast_approx::<P7>() };
+        let simd_approx_7 = unsafe { Simd::<f64, 4>::splat(x).sin_fast_approx::<P7>() };
+
+        let exact = x.sin();
+
+        assert!(
+            (exact - approx_3).abs() <= MAX_SIN_ERROR_3,
+            "Error greater than set maximum: true: {exact}, approx: {approx_3}, x: {x}"
+        );
+        assert!(
+            (exact - approx_7).abs() <= MAX_SIN_ERROR_7,
+            "Error greater than set maximum: true: {exact}, approx: {approx_7}, x: {x}"
+        );
+        // the scalar impls can be contracted into fused multiply-adds, so the SIMD results are
+        // compared against the exact value too
+        for approx in simd_approx_7.to_array() {
+            assert!(
+                (exact - approx).abs() <= MAX_SIN_ERROR_7,
+                "Error greater than set maximum: true: {exact}, approx: {approx}, x: {x}"
+            );
+        }
+
+        let x = rng.gen_range(EXP2_RANGE);
+
+        let approx_3 = unsafe { x.exp2_fast_approx::<P3>() };
+        let approx_7 = unsafe { x.exp2_fast_approx::<P7>() };
+
+        let exact = x.exp2();
+
+        assert!(
+            ((exact - approx_3) / exact).abs() <= MAX_EXP2_ERROR_3,
+            "Error greater than set maximum: true: {exact}, approx: {approx_3}, x: {x}"
+        );
+        assert!(
+            ((exact - approx_7) / exact).abs() <= MAX_EXP2_ERROR_7,
+            "Error greater than set maximum: true: {exact}, approx: {approx_7}, x: {x}"
+        );
+    }
+}
+
+#[inline(never)]
+#[test]
+pub fn pow_error() {
+    const BASE_RANGE: Range<f32> = 0.125..8.0;
+    const EXPONENT_RANGE: Range<f32> = -4.0..4.0;
+    // the log2 error is scaled by the exponent, so this is much larger than the exp2 error
+    const MAX_ERROR_3: f32 = 5.0e-5_f32;
+    const MAX_ERROR_POWI: f32 = 1.0e-6_f32;
+
+    let mut rng = thread_rng();
+    for _i in 0..ITERS {
+        let x = rng.gen_range(BASE_RANGE);
+        let y = rng.gen_range(EXPONENT_RANGE);
+
+        let approx_3 = unsafe { x.pow_fast_approx::<P3>(y) };
+        let approx_powi = unsafe { x.powi_const::<-5>() };
+        let approx_powf = unsafe { x.powf_const::<P3, 11, 5>() };
+
+        let exact = x.powf(y);
+        let exact_powi = x.powi(-5);
+        let exact_powf = x.powf(2.2);
+
+        assert!(
+            ((exact - approx_3) / exact).abs() <= MAX_ERROR_3,
+            "Error greater than set maximum: true: {exact}, approx: {approx_3}, x: {x}, y: {y}"
+        );
+        assert!(
+            ((exact_powi - approx_powi) / exact_powi).abs() <= MAX_ERROR_POWI,
+            "Error greater than set maximum: true: {exact_powi}, approx: {approx_powi}, x: {x}"
+        );
+        assert!(
+            ((exact_powf - approx_powf) / exact_powf).abs() <= MAX_ERROR_3,
+            "Error greater than set maximum: true: {exact_powf}, approx: {approx_powf}, x: {x}"
+        );
+    }
+}
+
+#[inline(never)]
+#[test]
+pub fn hyperbolic_error() {
+    const RANGE: Range<f32> = -80.0..80.0;
+    const TANH_RANGE: Range<f32> = -20.0..20.0;
+    const MAX_ERRORS: [f32; 4] = [2.5e-3_f32, 1.1e-4_f32, 6.5e-6_f32, 4.2e-6_f32];
+    const MAX_TANH_ERRORS: [f32; 4] = [8.7e-4_f32, 3.8e-5_f32, 1.5e-6_f32, 4.5e-7_f32];
+
+    let mut rng = thread_rng();
+    for _i in 0..ITERS {
+        let x = rng.gen_range(RANGE);
+        let tanh_x = rng.gen_range(TANH_RANGE);
+
+        let sinh_approx = unsafe {
+            [
+                x.sinh_fast_approx::<P0>(),
+                x.sinh_fast_approx::<P1>(),
+                x.sinh_fast_approx::<P2>(),
+                x.sinh_fast_approx::<P3>(),
+            ]
+        };
+        let cosh_approx = unsafe {
+            [
+                x.cosh_fast_approx::<P0>(),
+                x.cosh_fast_approx::<P1>(),
+                x.cosh_fast_approx::<P2>(),
+                x.cosh_fast_approx::<P3>(),
+            ]
+        };
+        let tanh_approx = unsafe {
+            [
+                tanh_x.tanh_fast_approx::<P0>(),
+                tanh_x.tanh_fast_approx::<P1>(),
+                tanh_x.tanh_fast_approx::<P2>(),
+                tanh_x.tanh_fast_approx::<P3>(),
+            ]
+        };
+
+        let sinh_exact = (x as f64).sinh();
+        let cosh_exact = (x as f64).cosh();
+        let tanh_exact = (tanh_x as f64).tanh();
+
+        for i in 0..4 {
+            for (exact, approx) in [(sinh_exact, sinh_approx[i]), (cosh_exact, cosh_approx[i])] {
+                assert!(
+                    ((exact - approx as f64) / exact).abs() <= MAX_ERRORS[i] as f64,
+                    "Error greater than set maximum: true: {exact}, approx: {approx}, x: {x}"
+                );
+            }
+            assert!(
+                (tanh_exact - tanh_approx[i] as f64).abs() <= MAX_TANH_ERRORS[i] as f64,
+                "Error greater than set maximum: true: {tanh_exact}, approx: {}, x: {tanh_x}",
+                tanh_approx[i]
+            );
+        }
+    }
+
+    // sinh should be odd and exact at 0, including for inputs small enough to cancel out
+    for x in [1.0e-30_f32, 1.0e-7, 1.0e-3, 0.5, 0.999] {
+        let simd_x = f32x4::splat(x);
+        unsafe {
+            assert_eq!(x.sinh_fast_approx::<P0>(), -(-x).sinh_fast_approx::<P0>());
+            assert_eq!(x.sinh_fast_approx::<P3>(), -(-x).sinh_fast_approx::<P3>());
+            assert_eq!(
+                simd_x.sinh_fast_approx::<P3>(),
+                -(-simd_x).sinh_fast_approx::<P3>()
+            );
+            assert_eq!(
+                (x as f64).sinh_fast_approx::<P7>(),
+                -(-x as f64).sinh_fast_approx::<P7>()
+            );
+        }
+
+        let exact = (x as f64).sinh();
+        for approx in unsafe {
+            [
+                x.sinh_fast_approx::<P3>() as f64,
+                simd_x.sinh_fast_approx::<P3>()[0] as f64,
+                (x as f64).sinh_fast_approx::<P7>(),
+            ]
+        } {
+            assert!(
+                ((exact - approx) / exact).abs() <= MAX_ERRORS[3] as f64,
+                "Error greater than set maximum: true: {exact}, approx: {approx}, x: {x}"
+            );
+        }
+    }
+    for zero in [0.0_f32, -0.0] {
+        unsafe {
+            assert_eq!(zero.sinh_fast_approx::<P0>(), 0.0);
+            assert_eq!(zero.sinh_fast_approx::<P3>(), 0.0);
+            assert_eq!(
+                f32x4::splat(zero).sinh_fast_approx::<P3>(),
+                f32x4::splat(0.0)
+            );
+            assert_eq!((zero as f64).sinh_fast_approx::<P7>(), 0.0);
+        }
+    }
+
+    // large inputs should saturate instead of producing NaN
+    for x in [30.0_f32, 1.0e10, f32::MAX] {
+        assert_eq!(unsafe { x.tanh_fast_approx::<P0>() }, 1.0);
+        assert_eq!(unsafe { (-x).tanh_fast_approx::<P3>() }, -1.0);
+        assert_eq!(
+            unsafe { Simd::<f32, 4>::splat(x).tanh_fast_approx::<P3>() },
+            Simd::splat(1.0)
+        );
+    }
+}
+
+#[inline(never)]
+#[test]
+pub fn root_error() {
+    const RANGE: Range<f32> = 1.0e-30..1.0e30;
+    const MAX_SQRT_ERRORS: [f32; 4] = [3.5e-2_f32, 1.8e-3_f32, 4.8e-6_f32, 3.0e-7_f32];
+    const MAX_CBRT_ERRORS: [f32; 4] = [6.9e-2_f32, 4.7e-3_f32, 2.3e-5_f32, 5.0e-7_f32];
+
+    let mut rng = thread_rng();
+    for _i in 0..ITERS {
+        let x = rng.gen_range(RANGE);
+        let signed_x = if rng.gen() { x } else { -x };
+
+        let sqrt_approx = unsafe {
+            [
+                x.sqrt_fast_approx::<P0>(),
+                x.sqrt_fast_approx::<P1>(),
+                x.sqrt_fast_approx::<P2>(),
+                x.sqrt_fast_approx::<P3>(),
+            ]
+        };
+        let rsqrt_approx = unsafe {
+            [
+                x.rsqrt_fast_approx::<P0>(),
+                x.rsqrt_fast_approx::<P1>(),
+                x.rsqrt_fast_approx::<P2>(),
+                x.rsqrt_fast_approx::<P3>(),
+            ]
+        };
+        let cbrt_approx = unsafe {
+            [
+                signed_x.cbrt_fast_approx::<P0>(),
+                signed_x.cbrt_fast_approx::<P1>(),
+                signed_x.cbrt_fast_approx::<P2>(),
+                signed_x.cbrt_fast_approx::<P3>(),
+            ]
+        };
+
+        // the exact values are calculated as f64 to keep their rounding out of the error
+        let sqrt_exact = (x as f64).sqrt();
+        let rsqrt_exact = 1.0 / (x as f64).sqrt();
+        let cbrt_exact = (signed_x as f64).cbrt();
+
+        for i in 0..4 {
+            for (exact, approx, max_error) in [
+                (sqrt_exact, sqrt_approx[i], MAX_SQRT_ERRORS[i]),
+                (rsqrt_exact, rsqrt_approx[i], MAX_SQRT_ERRORS[i]),
+                (cbrt_exact, cbrt_approx[i], MAX_CBRT_ERRORS[i]),
+            ] {
+                assert!(
+                    ((exact - approx as f64) / exact).abs() <= max_error as f64,
+                    "Error greater than set maximum: true: {exact}, approx: {approx}, x: {x}"
+                );
+            }
+        }
+    }
+
+    // the initial guesses only work for normal inputs, so the roots of subnormals and 0 are
+    // checked separately, for both the scalar and simd paths
+    for x in [
+        f32::from_bits(1),
+        1.0e-45,
+        1.0e-40,
+        3.0e-39,
+        1.1e-38,
+        -1.0e-40,
+        -1.1e-38,
+    ] {
+        let exact_sqrt = (x as f64).abs().sqrt();
+        let exact_cbrt = (x as f64).cbrt();
+        let simd_x = f32x4::splat(x);
+        let simd_abs_x = f32x4::splat(x.abs());
+
+        let results = unsafe {
+            [
+                (
+                    x.abs().sqrt_fast_approx::<P2>(),
+                    exact_sqrt,
+                    MAX_SQRT_ERRORS[2],
+                ),
+                (
+                    x.abs().sqrt_fast_approx::<P3>(),
+                    exact_sqrt,
+                    MAX_SQRT_ERRORS[3],
+                ),
+                (
+                    x.abs().rsqrt_fast_approx::<P3>(),
+                    1.0 / exact_sqrt,
+                    MAX_SQRT_ERRORS[3],
+                ),
+                (x.cbrt_fast_approx::<P2>(), exact_cbrt, MAX_CBRT_ERRORS[2]),
+                (x.cbrt_fast_approx::<P3>(), exact_cbrt, MAX_CBRT_ERRORS[3]),
+                (
+                    simd_abs_x.sqrt_fast_approx::<P3>()[0],
+                    exact_sqrt,
+                    MAX_SQRT_ERRORS[3],
+                ),
+                (
+                    simd_abs_x.rsqrt_fast_approx::<P3>()[0],
+                    1.0 / exact_sqrt,
+                    MAX_SQRT_ERRORS[3],
+                ),
+                (
+                    simd_x.cbrt_fast_approx::<P3>()[0],
+                    exact_cbrt,
+                    MAX_CBRT_ERRORS[3],
+                ),
+            ]
+        };
+        for (approx, exact, max_error) in results {
+            assert!(
+                ((exact - approx as f64) / exact).abs() <= max_error as f64,
+                "Error greater than set maximum: true: {exact}, approx: {approx}, x: {x}"
+            );
+        }
+    }
+
+    for x in [
+        f64::from_bits(1),
+        5.0e-320,
+        1.0e-310,
+        2.2e-308,
+        -5.0e-320,
+        -2.2e-308,
+    ] {
+        let exact_sqrt = x.abs().sqrt();
+        let exact_cbrt = x.cbrt();
+        let simd_x = f64x4::splat(x);
+        let simd_abs_x = f64x4::splat(x.abs());
+
+        let results = unsafe {
+            [
+                (x.abs().sqrt_fast_approx::<P3>(), exact_sqrt),
+                (x.abs().rsqrt_fast_approx::<P3>(), 1.0 / exact_sqrt),
+                (x.cbrt_fast_approx::<P3>(), exact_cbrt),
+                (simd_abs_x.sqrt_fast_approx::<P3>()[0], exact_sqrt),
+                (simd_abs_x.rsqrt_fast_approx::<P3>()[0], 1.0 / exact_sqrt),
+                (simd_x.cbrt_fast_approx::<P3>()[0], exact_cbrt),
+            ]
+        };
+        for (approx, exact) in results {
+            assert!(
+                ((exact - approx) / exact).abs() <= MAX_CBRT_ERRORS[3] as f64,
+                "Error greater than set maximum: true: {exact}, approx: {approx}, x: {x}"
+            );
+        }
+    }
+
+    for zero in [0.0_f32, -0.0] {
+        unsafe {
+            assert_eq!(zero.sqrt_fast_approx::<P3>(), 0.0);
+            assert_eq!(zero.cbrt_fast_approx::<P3>(), 0.0);
+            assert_eq!(
+                f32x4::splat(zero).sqrt_fast_approx::<P3>(),
+                f32x4::splat(0.0)
+            );
+            assert_eq!(
+                f32x4::splat(zero).cbrt_fast_approx::<P3>(),
+                f32x4::splat(0.0)
+            );
+            assert_eq!((zero as f64).sqrt_fast_approx::<P3>(), 0.0);
+            assert_eq!((zero as f64).cbrt_fast_approx::<P3>(), 0.0);
+            assert_eq!(
+                f64x4::splat(zero as f64).sqrt_fast_approx::<P3>(),
+                f64x4::splat(0.0)
+            );
+            assert_eq!(
+                f64x4::splat(zero as f64).cbrt_fast_approx::<P3>(),
+                f64x4::splat(0.0)
+            );
+        }
+    }
+}
+
+#[inline(never)]
+#[test]
+pub fn simd_and_scalar_error() {
+    let mut rng = thread_rng();
+
+    // the SIMD impls are separate from the scalar ones. the scalar impls use fast-math
+    // intrinsics, which can be contracted into fused multiply-adds, so the results can round
+    // differently, and both are compared against the exact function instead. the errors are
+    // relative to the larger of the exact value and `min_scale`, which is 1 for the functions
+    // with results that cross 0.
+    #[inline(always)]
+    fn check<const LANES: usize>(
+        name: &str,
+        range: Range<f32>,
+        max_error: f64,
+        min_scale: f64,
+        simd: impl Fn(Simd<f32, LANES>) -> Simd<f32, LANES>,
+        scalar: impl Fn(f32) -> f32,
+        exact: impl Fn(f32) -> f64,
+    ) where
+        LaneCount<LANES>: SupportedLaneCount,
+    {
+        let mut rng = thread_rng();
+        for _i in 0..ITERS / LANES {
+            let x = Simd::from_array([0;LANES].map(|_| rng.gen_range(range.clone())));
+            let simd_result = simd(x);
+
+            for lane in 0..LANES {
+                let exact = exact(x[lane]);
+                for (path, approx) in [("scalar", scalar(x[lane])), ("SIMD", simd_result[lane])] {
+                    let error = ((exact - approx as f64) / exact.abs().max(min_scale)).abs();
+                    assert!(
+                        error <= max_error,
+                        "Error greater than set maximum in {path} {name}: true: {exact}, approx: {approx}, x: {}",
+                        x[lane]
+                    );
+                }
+            }
+        }
+    }
+
+    unsafe {
+        // the fast range reduction loses precision as the input gets further from 0
+        check::<8>(
+            "sin",
+            -100.0..100.0,
+            1.6e-5,
+            1.0,
+            |x| x.sin_fast_approx::<P3>(),
+            |x| x.sin_fast_approx::<P3>(),
+            |x| (x as f64).sin(),
+        );
+        check::<8>(
+            "sin_wide",
+            -1.0e6..1.0e6,
+            2.5e-7,
+            1.0,
+            |x| x.sin_wide_fast_approx::<P3>(),
+            |x| x.sin_wide_fast_approx::<P3>(),
+            |x| (x as f64).sin(),
+        );
+        check::<8>(
+            "tan",
+            -1.5..1.5,
+            2.2e-6,
+            1.0,
+            |x| x.tan_fast_approx::<P3>(),
+            |x| x.tan_fast_approx::<P3>(),
+            |x| (x as f64).tan(),
+        );
+        check::<8>(
+            "tan_restrict",
+            -1.5..1.5,
+            2.2e-6,
+            1.0,
+            |x| x.tan_restrict_fast_approx::<P3>(),
+            |x| x.tan_restrict_fast_approx::<P3>(),
+            |x| (x as f64).tan(),
+        );
+        check::<8>(
+            "atan",
+            -100.0..100.0,
+            5.0e-7,
+            1.0,
+            |x| x.atan_fast_approx::<P3>(),
+            |x| x.atan_fast_approx::<P3>(),
+            |x| (x as f64).atan(),
+        );
+        check::<8>(
+            "acos",
+            -1.0..1.0,
+            4.3e-7,
+            1.0,
+            |x| x.acos_fast_approx::<P3>(),
+            |x| x.acos_fast_approx::<P3>(),
+            |x| (x as f64).acos(),
+        );
+        check::<8>(
+            "log2",
+            1.0e-30..1.0e30,
+            2.8e-7,
+            1.0,
+            |x| x.log2_fast_approx::<P3>(),
+            |x| x.log2_fast_approx::<P3>(),
+            |x| (x as f64).log2(),
+        );
+        check::<8>(
+            "exp2",
+            -125.0..127.0,
+            3.5e-7,
+            f64::MIN_POSITIVE,
+            |x| x.exp2_fast_approx::<P3>(),
+            |x| x.exp2_fast_approx::<P3>(),
+            |x| (x as f64).exp2(),
+        );
+        check::<8>(
+            "tanh",
+            -20.0..20.0,
+            3.1e-7,
+            1.0,
+            |x| x.tanh_fast_approx::<P3>(),
+            |x| x.tanh_fast_approx::<P3>(),
+            |x| (x as f64).tanh(),
+        );
+        check::<8>(
+            "rsqrt",
+            1.0e-30..1.0e30,
+            2.3e-7,
+            f64::MIN_POSITIVE,
+            |x| x.rsqrt_fast_approx::<P3>(),
+            |x| x.rsqrt_fast_approx::<P3>(),
+            |x| 1.0 / (x as f64).sqrt(),
+        );
+        check::<8>(
+            "cbrt",
+            -1.0e30..1.0e30,
+            6.3e-7,
+            f64::MIN_POSITIVE,
+            |x| x.cbrt_fast_approx::<P3>(),
+            |x| x.cbrt_fast_approx::<P3>(),
+            |x| (x as f64).cbrt(),
+        );
+        // the second argument covers every quadrant
+        check::<8>(
+            "atan2",
+            -100.0..100.0,
+            5.1e-7,
+            1.0,
+            |x| x.atan2_fast_approx::<P3>(Simd::splat(1.0) - x * Simd::splat(0.1)),
+            |x| x.atan2_fast_approx::<P3>(1.0 - x * 0.1),
+            |x| (x as f64).atan2((1.0 - x * 0.1) as f64),
+        );
+    }
+
+    // tan scales the input by 2/PI, so the rounding in the SIMD range reduction has to stay exact
+    // for multiples past 2^22, or the parity of the multiple picks the wrong branch
+    for _i in 0..ITERS / 8 {
+        let x = f32x8::from_array([0; 8].map(|_| rng.gen_range(6.6e6..8_388_608.0_f32)));
+        let result = unsafe { x.tan_fast_approx::<P3>() };
+
+        for lane in 0..8 {
+            let scalar = unsafe { x[lane].tan_fast_approx::<P3>() };
+            assert!(
+                (result[lane] - scalar).abs() <= 1.0e-5 * scalar.abs().max(1.0),
+                "SIMD result differs from scalar in tan: scalar: {scalar}, simd: {}, x: {}",
+                result[lane],
+                x[lane]
+            );
+        }
+    }
+}
+
 #[inline(never)]
 #[test]
 pub fn simd_ilog_error() {
@@ -153,6 +895,1134 @@ pub fn simd_ilog_error() {
     }
 }
 
+#[inline(never)]
+#[test]
+pub fn signed_int() {
+    let mut rng = thread_rng();
+    for _i in 0..ITERS {
+        let x = (rng.next_u32() >> 1).max(1) as i32;
+        let x_64 = (rng.next_u64() >> 1).max(1) as i64;
+        assert_eq!(x.ilog_const_base::<3>(), x.ilog(3) as i32);
+        assert_eq!(x.ilog_const_base::<10>(), x.ilog10() as i32);
+        assert_eq!(x_64.ilog_const_base::<7>(), x_64.ilog(7) as i64);
+
+        let simd_x = Simd::from_array([0; 8].map(|_| (rng.next_u32() >> 1).max(1) as i32));
+        let exact = Simd::from_array(simd_x.to_array().map(|x| x.ilog(3) as i32));
+        assert_eq!(simd_x.ilog_const_base::<3>(), exact);
+    }
+
+    for exponent in 0..=19 {
+        assert_eq!(exponent.exp_const_coeff::<3>(), 3_i32.pow(exponent as u32));
+    }
+    assert_eq!(
+        Simd::from_array([0_i64, 1, 5, 39]).exp_const_coeff::<3>(),
+        Simd::from_array([1, 3, 243, 3_i64.pow(39)])
+    );
+
+    // negative coefficients give negative results for odd exponents
+    for exponent in 0..=19 {
+        let exact = (-3_i32).pow(exponent as u32);
+        assert_eq!(exponent.exp_const_signed_coeff::<-3>(), exact);
+        assert_eq!(exponent.checked_exp_const_signed_coeff::<-3>(), Some(exact));
+    }
+    assert_eq!(7_i8.checked_exp_const_signed_coeff::<-2>(), Some(i8::MIN));
+    assert_eq!(8_i8.checked_exp_const_signed_coeff::<-2>(), None);
+    assert_eq!(9_i8.saturating_exp_const_signed_coeff::<-2>(), i8::MIN);
+    assert_eq!(8_i8.saturating_exp_const_signed_coeff::<-2>(), i8::MAX);
+    let exp_simd = Simd::from_array([0_i64, 1, 5, 39, 40, -1]);
+    let (result, valid) = exp_simd.checked_exp_const_signed_coeff::<-3>();
+    for i in 0..6 {
+        let exact = (exp_simd[i] >= 0)
+            .then(|| (-3_i64).checked_pow(exp_simd[i] as u32))
+            .flatten();
+        assert_eq!(valid.test(i).then_some(result[i]), exact);
+    }
+    assert_eq!(
+        [0_i16, 3, 5].exp_const_signed_coeff::<-7>(),
+        [1, -343, -16_807]
+    );
+
+    assert!(std::panic::catch_unwind(|| (-5_i32).ilog_const_base::<3>()).is_err());
+    assert!(std::panic::catch_unwind(|| 0_i16.ilog_const_base::<3>()).is_err());
+    assert!(std::panic::catch_unwind(|| (-1_i32).exp_const_coeff::<3>()).is_err());
+    assert!(std::panic::catch_unwind(|| 20_i32.exp_const_coeff::<3>()).is_err());
+    assert!(std::panic::catch_unwind(|| (-1_i32).exp_const_signed_coeff::<-3>()).is_err());
+    assert!(std::panic::catch_unwind(|| 1_i8.exp_const_signed_coeff::<-200>()).is_err());
+    assert!(
+        std::panic::catch_unwind(|| Simd::from_array([4_i32, -4]).ilog_const_base::<3>()).is_err()
+    );
+}
+
+#[inline(never)]
+#[test]
+pub fn wide_and_narrow_int() {
+    let mut rng = thread_rng();
+    for _i in 0..ITERS {
+        let x = ((rng.next_u64() as u128) << 64 | rng.next_u64() as u128) >> (rng.next_u32() % 128);
+        let x = x.max(1);
+        assert_eq!(x.ilog_const_base::<3>(), x.ilog(3) as u128);
+        assert_eq!(x.ilog_const_base::<10>(), x.ilog10() as u128);
+        assert_eq!(x.ilog_const_base::<2>(), x.ilog2() as u128);
+
+        let x_usize = (rng.next_u64() as usize).max(1);
+        assert_eq!(x_usize.ilog_const_base::<7>(), x_usize.ilog(7) as usize);
+
+        let x_u8 = Simd::from_array([0; 16].map(|_| (rng.next_u32() as u8).max(1)));
+        let exact = Simd::from_array(x_u8.to_array().map(|x| x.ilog(3) as u8));
+        assert_eq!(x_u8.ilog_const_base::<3>(), exact);
+
+        let x_u16 = Simd::from_array([0; 16].map(|_| (rng.next_u32() as u16).max(1)));
+        let exact = Simd::from_array(x_u16.to_array().map(|x| x.ilog(10) as u16));
+        assert_eq!(x_u16.ilog_const_base::<10>(), exact);
+
+        let x_usize = Simd::from_array([0; 4].map(|_| (rng.next_u64() as usize).max(1)));
+        let exact = Simd::from_array(x_usize.to_array().map(|x| x.ilog(5) as usize));
+        assert_eq!(x_usize.ilog_const_base::<5>(), exact);
+    }
+
+    for exponent in 0..=80 {
+        let power = 3_u128.pow(exponent as u32);
+        assert_eq!(exponent.exp_const_coeff::<3>(), power);
+        assert_eq!(power.ilog_const_base::<3>(), exponent);
+        if exponent != 0 {
+            assert_eq!((power - 1).ilog_const_base::<3>(), exponent - 1);
+        }
+    }
+    assert_eq!(
+        u128::MAX.ilog_const_base::<10>(),
+        u128::MAX.ilog10() as u128
+    );
+    assert_eq!(
+        Simd::from_array([0_u8, 1, 2, 5]).exp_const_coeff::<3>(),
+        Simd::from_array([1, 3, 9, 243])
+    );
+    assert_eq!(
+        Simd::from_array([0_usize, 1, 13, 40]).exp_const_coeff::<3>(),
+        Simd::from_array([1, 3, 3_usize.pow(13), 3_usize.pow(40)])
+    );
+}
+
+#[inline(never)]
+#[test]
+pub fn ilog_var_base() {
+    let mut rng = thread_rng();
+    for _i in 0..ITERS {
+        let base = rng.gen_range(2..1000_u32);
+        let x = rng.next_u32().max(1);
+        assert_eq!(x.ilog_var_base(base), x.ilog(base), "x: {x}, base: {base}");
+
+        let shift = rng.gen_range(0..63);
+        let base_64 = rng.gen_range(2..u64::MAX >> shift);
+        let x_64 = rng.next_u64().max(1);
+        assert_eq!(x_64.ilog_var_base(base_64), x_64.ilog(base_64) as u64);
+
+        let shift = rng.gen_range(0..127);
+        let base_128 = rng.gen_range(2..u128::MAX >> shift);
+        let x_128 = ((rng.next_u64() as u128) << 64 | rng.next_u64() as u128).max(1);
+        assert_eq!(x_128.ilog_var_base(base_128), x_128.ilog(base_128) as u128);
+
+        // exact powers and the values right below them are where the estimate is closest to
+        // being wrong
+        let exponent = rng.gen_range(1..=x.ilog(base).max(1));
+        let power = base.pow(exponent);
+        assert_eq!(power.ilog_var_base(base), exponent);
+        assert_eq!((power - 1).ilog_var_base(base), exponent - 1);
+
+        let base_simd = Simd::from_array([0; 8].map(|_| rng.gen_range(2..100_u32)));
+        let x_simd = Simd::from_array([0; 8].map(|_| rng.next_u32().max(1)));
+        let exact = Simd::from_array(array::from_fn(|i| x_simd[i].ilog(base_simd[i])));
+        assert_eq!(x_simd.ilog_var_base(base_simd), exact);
+
+        let base_simd = Simd::from_array([0; 4].map(|_| rng.gen_range(2..1 << 40_u64)));
+        let x_simd = Simd::from_array([0; 4].map(|_| rng.next_u64().max(1)));
+        let exact = Simd::from_array(array::from_fn(|i| x_simd[i].ilog(base_simd[i]) as u64));
+        assert_eq!(x_simd.ilog_var_base(base_simd), exact);
+
+        let base_simd = Simd::from_array([0; 16].map(|_| rng.gen_range(2..20_u8)));
+        let x_simd = Simd::from_array([0; 16].map(|_| (rng.next_u32() as u8).max(1)));
+        let exact = Simd::from_array(array::from_fn(|i| x_simd[i].ilog(base_simd[i]) as u8));
+        assert_eq!(x_simd.ilog_var_base(base_simd), exact);
+    }
+
+    assert_eq!(u128::MAX.ilog_var_base(2), 127);
+    assert_eq!(100_i32.ilog_var_base(10), 2);
+
+    // the next power overflows for the largest powers of each base
+    let bases = Simd::from_array([2, 3, 10, 255, 65_535, 65_536, 1 << 31, u32::MAX]);
+    let powers = Simd::from_array(array::from_fn(|i| bases[i].pow(u32::MAX.ilog(bases[i]))));
+    let exact = Simd::from_array(array::from_fn(|i| u32::MAX.ilog(bases[i])));
+    assert_eq!(powers.ilog_var_base(bases), exact);
+    assert_eq!(Simd::splat(u32::MAX).ilog_var_base(bases), exact);
+    assert!(std::panic::catch_unwind(|| 100_u32.ilog_var_base(1)).is_err());
+    assert!(std::panic::catch_unwind(|| 0_u32.ilog_var_base(10)).is_err());
+    assert!(std::panic::catch_unwind(|| (-8_i64).ilog_var_base(2)).is_err());
+}
+
+#[inline(never)]
+#[test]
+pub fn ipow() {
+    let mut rng = thread_rng();
+    for _i in 0..ITERS {
+        // small bases and exponents land close to the overflow boundary
+        let base = rng.gen_range(0..40_u32);
+        let exp = rng.gen_range(0..40_u32);
+        assert_eq!(base.wrapping_ipow(exp), base.wrapping_pow(exp));
+        assert_eq!(base.checked_ipow(exp), base.checked_pow(exp));
+        assert_eq!(base.saturating_ipow(exp), base.saturating_pow(exp));
+
+        let base_i64 = rng.gen_range(-40..40_i64);
+        let exp_i64 = rng.gen_range(0..80_i64);
+        let exp_u32 = exp_i64 as u32;
+        assert_eq!(
+            base_i64.wrapping_ipow(exp_i64),
+            base_i64.wrapping_pow(exp_u32)
+        );
+        assert_eq!(
+            base_i64.checked_ipow(exp_i64),
+            base_i64.checked_pow(exp_u32)
+        );
+        assert_eq!(
+            base_i64.saturating_ipow(exp_i64),
+            base_i64.saturating_pow(exp_u32)
+        );
+
+        let base_simd = Simd::from_array([0; 8].map(|_| rng.gen_range(0..40_u32)));
+        let exp_simd = Simd::from_array([0; 8].map(|_| rng.gen_range(0..40_u32)));
+        let (result, valid) = base_simd.checked_ipow(exp_simd);
+        for i in 0..8 {
+            let exact = base_simd[i].checked_pow(exp_simd[i]);
+            assert_eq!(valid.test(i).then_some(result[i]), exact);
+        }
+        let exact = array::from_fn(|i| base_simd[i].saturating_pow(exp_simd[i]));
+        assert_eq!(base_simd.saturating_ipow(exp_simd).to_array(), exact);
+
+        let base_simd = Simd::from_array([0; 4].map(|_| rng.gen_range(0..40_u64)));
+        let exp_simd = Simd::from_array([0; 4].map(|_| rng.gen_range(0..80_u64)));
+        let exact = array::from_fn(|i| base_simd[i].wrapping_pow(exp_simd[i] as u32));
+        assert_eq!(base_simd.wrapping_ipow(exp_simd).to_array(), exact);
+        let (result, valid) = base_simd.checked_ipow(exp_simd);
+        for i in 0..4 {
+            let exact = base_simd[i].checked_pow(exp_simd[i] as u32);
+            assert_eq!(valid.test(i).then_some(result[i]), exact);
+        }
+
+        let base_simd = Simd::from_array([0; 16].map(|_| rng.gen_range(-20..20_i8)));
+        let exp_simd = Simd::from_array([0; 16].map(|_| rng.gen_range(0..10_i8)));
+        let (result, valid) = base_simd.checked_ipow(exp_simd);
+        for i in 0..16 {
+            let exact = base_simd[i].checked_pow(exp_simd[i] as u32);
+            assert_eq!(valid.test(i).then_some(result[i]), exact);
+        }
+        let exact = array::from_fn(|i| base_simd[i].saturating_pow(exp_simd[i] as u32));
+        assert_eq!(base_simd.saturating_ipow(exp_simd).to_array(), exact);
+    }
+
+    assert_eq!((-2_i32).ipow(31), i32::MIN);
+    assert_eq!(3_u128.ipow(80), 3_u128.pow(80));
+    assert_eq!(2_u128.checked_ipow(u128::MAX), None);
+    assert_eq!(1_u128.ipow(u128::MAX), 1);
+    assert_eq!(2_i32.checked_ipow(-1), None);
+    assert!(std::panic::catch_unwind(|| 2_u32.ipow(32)).is_err());
+    assert!(std::panic::catch_unwind(|| 2_i32.wrapping_ipow(-1)).is_err());
+    assert!(
+        std::panic::catch_unwind(|| Simd::from_array([2_u64, 3]).ipow(Simd::splat(64))).is_err()
+    );
+}
+
+#[inline(never)]
+#[test]
+pub fn integer_roots() {
+    // the floor of the root is the only value where root^N <= x < (root + 1)^N
+    fn is_root<const N: u32>(x: u128, root: u128) -> bool {
+        root.pow(N) <= x
+            && (root + 1)
+                .checked_pow(N)
+                .filter(|&next| next <= x)
+                .is_none()
+    }
+
+    let mut rng = thread_rng();
+    for _i in 0..ITERS {
+        let x = ((rng.next_u64() as u128) << 64 | rng.next_u64() as u128) >> (rng.next_u32() % 128);
+        assert!(is_root::<2>(x, x.isqrt()), "x: {x}");
+        assert!(is_root::<3>(x, x.icbrt()), "x: {x}");
+        assert!(is_root::<5>(x, x.iroot_const::<5>()), "x: {x}");
+
+        let x_64 = rng.next_u64() >> (rng.next_u32() % 64);
+        assert!(is_root::<2>(x_64 as u128, x_64.isqrt() as u128));
+        assert!(is_root::<4>(x_64 as u128, x_64.iroot_const::<4>() as u128));
+
+        let x_32 = rng.next_u32() >> (rng.next_u32() % 32);
+        assert!(is_root::<2>(x_32 as u128, x_32.isqrt() as u128));
+        assert!(is_root::<3>(x_32 as u128, x_32.icbrt() as u128));
+
+        // exact powers and the values right below them are where the estimate is closest to
+        // being wrong
+        let root = rng.gen_range(1..1_u64 << 16);
+        assert_eq!((root * root).isqrt(), root);
+        assert_eq!((root * root - 1).isqrt(), root - 1);
+        assert_eq!((root * root * root).icbrt(), root);
+        assert_eq!((root * root * root - 1).icbrt(), root - 1);
+
+        let root_128 = rng.next_u64() as u128;
+        assert_eq!((root_128 * root_128).isqrt(), root_128);
+        if root_128 != 0 {
+            assert_eq!((root_128 * root_128 - 1).isqrt(), root_128 - 1);
+        }
+
+        let x_simd = Simd::from_array([0; 8].map(|_| rng.next_u32() >> (rng.next_u32() % 32)));
+        let sqrt = x_simd.isqrt();
+        let cbrt = x_simd.icbrt();
+        let root_7 = x_simd.iroot_const::<7>();
+        for i in 0..8 {
+            assert!(is_root::<2>(x_simd[i] as u128, sqrt[i] as u128));
+            assert!(is_root::<3>(x_simd[i] as u128, cbrt[i] as u128));
+            assert!(is_root::<7>(x_simd[i] as u128, root_7[i] as u128));
+        }
+
+        let x_simd = Simd::from_array([0; 4].map(|_| rng.next_u64() >> (rng.next_u32() % 64)));
+        let sqrt = x_simd.isqrt();
+        let cbrt = x_simd.icbrt();
+        for i in 0..4 {
+            assert!(is_root::<2>(x_simd[i] as u128, sqrt[i] as u128));
+            assert!(is_root::<3>(x_simd[i] as u128, cbrt[i] as u128));
+        }
+
+        let x_simd = Simd::from_array([0; 16].map(|_| rng.next_u32() as u16));
+        let sqrt = x_simd.isqrt();
+        for i in 0..16 {
+            assert!(is_root::<2>(x_simd[i] as u128, sqrt[i] as u128));
+        }
+    }
+
+    assert_eq!(u128::MAX.isqrt(), u64::MAX as u128);
+    assert_eq!(u64::MAX.isqrt(), u32::MAX as u64);
+    assert_eq!(u32::MAX.isqrt(), u16::MAX as u32);
+    assert_eq!(
+        Simd::from_array([0_u32, 1, u32::MAX]).isqrt().to_array(),
+        [0, 1, 65535]
+    );
+    assert_eq!(255_u8.iroot_const::<9>(), 1);
+    assert_eq!(1000_i32.icbrt(), 10);
+    assert!(std::panic::catch_unwind(|| (-4_i32).isqrt()).is_err());
+    assert!(std::panic::catch_unwind(|| 4_u32.iroot_const::<0>()).is_err());
+}
+
+#[inline(never)]
+#[test]
+pub fn div_const() {
+    fn check<const D: u32>(rng: &mut ThreadRng) {
+        let x = rng.next_u32();
+        assert_eq!(x.div_const::<D>(), x / D);
+        assert_eq!(x.rem_const::<D>(), x % D);
+
+        let x_i64 = rng.next_u64() as i64;
+        assert_eq!(x_i64.div_const::<D>(), x_i64 / D as i64);
+        assert_eq!(x_i64.rem_const::<D>(), x_i64 % D as i64);
+
+        let x_u8 = rng.next_u32() as u8;
+        assert_eq!(x_u8.div_const::<D>(), (x_u8 as u32 / D) as u8);
+
+        let x_simd = Simd::from_array([0; 8].map(|_| rng.next_u32()));
+        assert_eq!(x_simd.div_const::<D>(), x_simd / Simd::splat(D));
+        assert_eq!(x_simd.rem_const::<D>(), x_simd % Simd::splat(D));
+
+        let x_simd = Simd::from_array([0; 4].map(|_| rng.next_u64()));
+        assert_eq!(x_simd.div_const::<D>(), x_simd / Simd::splat(D as u64));
+        assert_eq!(x_simd.rem_const::<D>(), x_simd % Simd::splat(D as u64));
+
+        let x_simd = Simd::from_array([0; 16].map(|_| rng.next_u32() as i16));
+        let exact = x_simd.to_array().map(|x| (x as i64 / D as i64) as i16);
+        assert_eq!(x_simd.div_const::<D>().to_array(), exact);
+        let exact = x_simd.to_array().map(|x| (x as i64 % D as i64) as i16);
+        assert_eq!(x_simd.rem_const::<D>().to_array(), exact);
+    }
+
+    let mut rng = thread_rng();
+    for _i in 0..ITERS {
+        check::<1>(&mut rng);
+        check::<3>(&mut rng);
+        check::<7>(&mut rng);
+        check::<10>(&mut rng);
+        check::<64>(&mut rng);
+        check::<641>(&mut rng);
+        check::<65535>(&mut rng);
+        check::<{ u32::MAX }>(&mut rng);
+    }
+
+    // the edges of the input range
+    assert_eq!(
+        Simd::from_array([u32::MAX, 0]).div_const::<7>().to_array(),
+        [u32::MAX / 7, 0]
+    );
+    assert_eq!(u64::MAX.div_const::<3>(), u64::MAX / 3);
+    assert_eq!(
+        Simd::from_array([u64::MAX]).div_const::<3>()[0],
+        u64::MAX / 3
+    );
+    assert_eq!(i32::MIN.div_const::<1>(), i32::MIN);
+    assert_eq!(Simd::from_array([i8::MIN]).div_const::<128>()[0], -1);
+    assert!(std::panic::catch_unwind(|| 4_u32.div_const::<0>()).is_err());
+}
+
+#[inline(never)]
+#[test]
+pub fn fast_divisor() {
+    let mut rng = thread_rng();
+    for _i in 0..ITERS {
+        // small divisors, powers of 2 and large divisors take different paths through the magic
+        let d = match rng.gen_range(0..3) {
+            0 => rng.gen_range(1..100_u32),
+            1 => 1 << rng.gen_range(0..32),
+            _ => rng.next_u32().max(1),
+        };
+        let divisor = FastDivisor::<u32>::new(d);
+        let x = rng.next_u32();
+        assert_eq!(x / divisor, x / d, "x: {x}, d: {d}");
+        assert_eq!(x % divisor, x % d, "x: {x}, d: {d}");
+
+        let x_simd = Simd::from_array([0; 8].map(|_| rng.next_u32()));
+        assert_eq!(x_simd / divisor, x_simd / Simd::splat(d));
+        assert_eq!(x_simd % divisor, x_simd % Simd::splat(d));
+
+        let d_64 = rng.next_u64() >> rng.gen_range(0..64);
+        let divisor_64 = FastDivisor::<u64>::new(d_64.max(1));
+        let x_64 = rng.next_u64();
+        assert_eq!(x_64 / divisor_64, x_64 / d_64.max(1));
+        let x_simd = Simd::from_array([0; 4].map(|_| rng.next_u64()));
+        assert_eq!(x_simd / divisor_64, x_simd / Simd::splat(d_64.max(1)));
+        assert_eq!(x_simd % divisor_64, x_simd % Simd::splat(d_64.max(1)));
+
+        let d_8 = (rng.next_u32() as u8).max(1);
+        let x_simd = Simd::from_array([0; 16].map(|_| rng.next_u32() as u8));
+        assert_eq!(
+            x_simd / FastDivisor::<u8>::new(d_8),
+            x_simd / Simd::splat(d_8)
+        );
+    }
+
+    assert_eq!(u64::MAX / FastDivisor::<u64>::new(u64::MAX), 1);
+    assert_eq!(u16::MAX / FastDivisor::<u16>::new(1), u16::MAX);
+    assert_eq!(FastDivisor::<usize>::new(7).divisor(), 7);
+    assert!(std::panic::catch_unwind(|| FastDivisor::<u32>::new(0)).is_err());
+}
+
+#[inline(never)]
+#[test]
+pub fn arrays() {
+    let mut rng = thread_rng();
+    for _i in 0..ITERS / 8 {
+        let x = [0.0_f32; 8].map(|_| rng.gen_range(-10.0..10.0));
+        let y = [0.0_f32; 8].map(|_| rng.gen_range(0.5..4.0));
+        // the lanes can be contracted into FMAs differently than the scalar functions
+        let assert_lanes = |array: [f32; 8], scalar: &dyn Fn(usize) -> f32| {
+            for (i, lane) in array.into_iter().enumerate() {
+                let expected = scalar(i);
+                let error = (lane - expected).abs() / expected.abs().max(1.0);
+                assert!(
+                    error <= 1.0e-6 || (lane.is_nan() && expected.is_nan()),
+                    "lane: {lane}, scalar: {expected}"
+                );
+            }
+        };
+
+        unsafe {
+            assert_lanes(x.sin_fast_approx::<P3>(), &|i| {
+                x[i].sin_fast_approx::<P3>()
+            });
+            assert_lanes(x.exp_fast_approx::<P2>(), &|i| {
+                x[i].exp_fast_approx::<P2>()
+            });
+            assert_lanes(x.powi_const::<3>(), &|i| x[i].powi_const::<3>());
+            let (sin, cos) = x.sincos_fast_approx::<P3>();
+            assert_lanes(sin, &|i| x[i].sincos_fast_approx::<P3>().0);
+            assert_lanes(cos, &|i| x[i].sincos_fast_approx::<P3>().1);
+            assert_lanes(y.pow_fast_approx::<P3>(x), &|i| {
+                y[i].pow_fast_approx::<P3>(x[i])
+            });
+            assert_lanes(y.powf_const::<P3, 2, 3>(), &|i| {
+                y[i].powf_const::<P3, 2, 3>()
+            });
+        }
+        assert_lanes(x.ln_fast_approx_checked::<P3>(), &|i| {
+            x[i].ln_fast_approx_checked::<P3>()
+        });
+        assert_lanes(x.atan2_fast_approx_checked::<P3>(y), &|i| {
+            x[i].atan2_fast_approx_checked::<P3>(y[i])
+        });
+
+        let x_int = [0_u32; 8].map(|_| (rng.next_u32() >> rng.gen_range(0..32)).max(1));
+        let exp = [0_u32; 8].map(|_| rng.gen_range(0..8));
+        assert_eq!(
+            x_int.ilog_const_base::<10>(),
+            x_int.map(|x| x.ilog_const_base::<10>())
+        );
+        assert_eq!(x_int.isqrt(), x_int.map(FastExactInt::isqrt));
+        assert_eq!(
+            x_int.wrapping_ipow(exp),
+            array::from_fn(|i| x_int[i].wrapping_ipow(exp[i]))
+        );
+        let (result, valid) = x_int.checked_ipow(exp);
+        for i in 0..8 {
+            assert_eq!(valid[i].then_some(result[i]), x_int[i].checked_ipow(exp[i]));
+        }
+
+        let d = rng.gen_range(1..1000_u32);
+        assert_eq!(x_int / FastDivisor::<u32>::new(d), x_int.map(|x| x / d));
+        assert_eq!(x_int % FastDivisor::<u32>::new(d), x_int.map(|x| x % d));
+    }
+
+    let (result, valid) = [0_i64, -5, 100].checked_ilog_const_base::<10>();
+    assert_eq!(valid, [false, false, true]);
+    assert_eq!(result[2], 2);
+}
+
+#[inline(never)]
+#[test]
+pub fn checked_const_int() {
+    let mut rng = thread_rng();
+    for _i in 0..ITERS {
+        // includes zeros and negatives, which are invalid for logs
+        let x = (rng.next_u32() * rng.gen_range(0..2)) >> rng.gen_range(0..32);
+        assert_eq!(x.checked_ilog_const_base::<10>(), x.checked_ilog10());
+        let x_i16 = rng.next_u32() as i16 >> rng.gen_range(0..16);
+        assert_eq!(
+            x_i16.checked_ilog_const_base::<3>(),
+            x_i16.checked_ilog(3).map(|l| l as i16)
+        );
+
+        let exp = rng.gen_range(0..40_u64);
+        assert_eq!(
+            exp.checked_exp_const_coeff::<3>(),
+            3_u64.checked_pow(exp as u32)
+        );
+        assert_eq!(
+            exp.saturating_exp_const_coeff::<3>(),
+            3_u64.saturating_pow(exp as u32)
+        );
+        let exp_i8 = rng.gen_range(-4..8_i8);
+        let exact = (exp_i8 >= 0)
+            .then(|| 3_i8.checked_pow(exp_i8 as u32))
+            .flatten();
+        assert_eq!(exp_i8.checked_exp_const_coeff::<3>(), exact);
+
+        let x_simd = Simd::from_array([0; 8].map(|_| rng.next_u32() * rng.gen_range(0..2)));
+        let (result, valid) = x_simd.checked_ilog_const_base::<5>();
+        for i in 0..8 {
+            assert_eq!(
+                valid.test(i).then_some(result[i]),
+                x_simd[i].checked_ilog(5)
+            );
+        }
+
+        let exp_simd = Simd::from_array([0; 16].map(|_| rng.gen_range(0..12_u16)));
+        let (result, valid) = exp_simd.checked_exp_const_coeff::<3>();
+        for i in 0..16 {
+            let exact = 3_u16.checked_pow(exp_simd[i] as u32);
+            assert_eq!(valid.test(i).then_some(result[i]), exact);
+        }
+        let exact = exp_simd
+            .to_array()
+            .map(|exp| 3_u16.saturating_pow(exp as u32));
+        assert_eq!(exp_simd.saturating_exp_const_coeff::<3>().to_array(), exact);
+
+        let exp_simd = Simd::from_array([0; 8].map(|_| rng.gen_range(-4..40_i64)));
+        let (result, valid) = exp_simd.checked_exp_const_coeff::<3>();
+        for i in 0..8 {
+            let exact = (exp_simd[i] >= 0)
+                .then(|| 3_i64.checked_pow(exp_simd[i] as u32))
+                .flatten();
+            assert_eq!(valid.test(i).then_some(result[i]), exact);
+        }
+    }
+
+    assert_eq!(0_u128.checked_ilog_const_base::<7>(), None);
+    assert_eq!((1_u128 << 64).checked_exp_const_coeff::<3>(), None);
+    assert_eq!(u8::MAX.saturating_exp_const_coeff::<2>(), u8::MAX);
+
+    // 0 and 1 have no largest power, so they don't use the table of powers
+    for exp in [0_u32, 1, 7, u32::MAX] {
+        assert_eq!(exp.checked_exp_const_coeff::<0>(), Some(0_u32.pow(exp)));
+        assert_eq!(exp.checked_exp_const_coeff::<1>(), Some(1));
+    }
+    assert_eq!(0_i64.exp_const_coeff::<0>(), 1);
+    assert_eq!(100_u128.saturating_exp_const_coeff::<1>(), 1);
+    assert_eq!((-1_i8).checked_exp_const_coeff::<1>(), None);
+    assert_eq!(
+        Simd::from_array([0_i32, -1])
+            .checked_ilog_const_base::<2>()
+            .1
+            .to_array(),
+        [false; 2]
+    );
+}
+
+#[inline(never)]
+#[test]
+pub fn checked_float() {
+    // the same as equality, but NaNs are equal to each other, and the slow paths can be as far
+    // apart as the most precise approximations
+    const SLOW_PATH_ERROR: f32 = 4.0e-6;
+
+    fn close(expected: f32, actual: f32) -> bool {
+        (expected.is_nan() && actual.is_nan())
+            || expected == actual
+            || (expected - actual).abs() <= SLOW_PATH_ERROR * expected.abs().max(1.0)
+    }
+
+    // the fast paths of the scalar impls can be contracted into fused multiply-adds, so they
+    // aren't compared against the SIMD impls directly, and both are checked against the exact
+    // function within the error of the approximations
+    const FAST_PATH_ERROR: f32 = 1.6e-5;
+
+    fn approx_close(expected: f32, actual: f32) -> bool {
+        (expected.is_nan() && actual.is_nan())
+            || expected == actual
+            || (expected - actual).abs() <= FAST_PATH_ERROR * expected.abs().max(1.0)
+    }
+
+    type SimdScalarAndExact = (fn(f32x8) -> f32x8, fn(f32) -> f32, fn(f32) -> f32);
+
+    let special = [
+        f32::NAN,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        0.0,
+        -0.0,
+        1.0e-40,
+    ];
+    for x in special {
+        assert!(x.sin_fast_approx_checked::<P3>().is_nan() == (x.is_nan() || x.is_infinite()));
+        assert!(close(x.exp_fast_approx_checked::<P3>(), x.exp()), "x: {x}");
+        assert!(
+            close(x.exp2_fast_approx_checked::<P3>(), x.exp2()),
+            "x: {x}"
+        );
+        assert!(close(x.ln_fast_approx_checked::<P3>(), x.ln()), "x: {x}");
+        assert!(
+            close(x.log2_fast_approx_checked::<P3>(), x.log2()),
+            "x: {x}"
+        );
+        assert!(
+            close(x.sqrt_fast_approx_checked::<P3>(), x.sqrt()),
+            "x: {x}"
+        );
+        assert!(
+            close(x.cbrt_fast_approx_checked::<P3>(), x.cbrt()),
+            "x: {x}"
+        );
+        assert!(
+            close(x.atan_fast_approx_checked::<P3>(), x.atan()),
+            "x: {x}"
+        );
+        assert!(
+            close(x.tanh_fast_approx_checked::<P3>(), x.tanh()),
+            "x: {x}"
+        );
+        for y in special {
+            let expected = y.atan2(x);
+            let actual = y.atan2_fast_approx_checked::<P3>(x);
+            // the fast path isn't exact, so only the special cases are compared exactly
+            assert!(
+                close(expected, actual) || (x.is_finite() && y.is_finite() && x != 0.0),
+                "y: {y}, x: {x}, expected: {expected}, actual: {actual}"
+            );
+        }
+    }
+    assert!(2.0_f32.asin_fast_approx_checked::<P3>().is_nan());
+    assert!((-1.0_f32).log10_fast_approx_checked::<P3>().is_nan());
+    assert_eq!(1000.0_f32.exp_fast_approx_checked::<P3>(), f32::INFINITY);
+    assert_eq!(10.0_f32.pow_fast_approx_checked::<P3>(100.0), f32::INFINITY);
+    assert_eq!(1.0e30_f32.powi_const_checked::<4>(), f32::INFINITY);
+    assert_eq!((-2.0_f32).powi_const_checked::<3>(), -8.0);
+
+    let mut rng = thread_rng();
+    for _i in 0..ITERS {
+        // inputs outside of the valid ranges of the unsafe functions
+        let x = rng.gen_range(1.0e8..1.0e30_f32);
+        assert!(close(x.sin_fast_approx_checked::<P3>(), x.sin()), "x: {x}");
+        assert!(close(x.cos_fast_approx_checked::<P3>(), x.cos()), "x: {x}");
+        let x = rng.gen_range(87.0..89.0_f32);
+        assert!(
+            close(x.sinh_fast_approx_checked::<P3>(), x.sinh()),
+            "x: {x}"
+        );
+        assert!(
+            close((-x).cosh_fast_approx_checked::<P3>(), x.cosh()),
+            "x: {x}"
+        );
+        let x = rng.gen_range(-150.0..-126.0_f32);
+        assert!(
+            close(x.exp2_fast_approx_checked::<P3>(), x.exp2()),
+            "x: {x}"
+        );
+
+        // inside the valid ranges, the results are exactly the fast approximations
+        let x = rng.gen_range(0.01..100.0_f32);
+        assert_eq!(x.ln_fast_approx_checked::<P2>(), unsafe {
+            x.ln_fast_approx::<P2>()
+        });
+        let x = rng.gen_range(-60.0..60.0_f32);
+        assert_eq!(x.sin_fast_approx_checked::<P2>(), unsafe {
+            x.sin_fast_approx::<P2>()
+        });
+
+        // past the bound where the fast range reduction loses precision, the wide reduction is
+        // used instead
+        let x = rng.gen_range(61.0..1.0e6_f32);
+        assert_eq!(x.sin_fast_approx_checked::<P2>(), unsafe {
+            x.sin_wide_fast_approx::<P2>()
+        });
+        assert_eq!(x.cos_fast_approx_checked::<P3>(), unsafe {
+            x.cos_wide_fast_approx::<P3>()
+        });
+        let (sin, cos) = unsafe { x.sincos_wide_fast_approx::<P3>() };
+        assert_eq!(x.tan_fast_approx_checked::<P3>(), sin / cos);
+
+        // SIMD lanes match the scalar results, whether or not they take the slow path
+        let x = Simd::from_array([0; 8].map(|_| match rng.gen_range(0..4) {
+            0 => special[rng.gen_range(0..special.len())],
+            1 => rng.gen_range(-1.0e30..1.0e30),
+            _ => rng.gen_range(-100.0..100.0),
+        }));
+        let functions: [SimdScalarAndExact; 6] = [
+            (
+                |x| x.sin_fast_approx_checked::<P3>(),
+                |x| x.sin_fast_approx_checked::<P3>(),
+                |x| x.sin(),
+            ),
+            (
+                |x| x.exp_fast_approx_checked::<P3>(),
+                |x| x.exp_fast_approx_checked::<P3>(),
+                |x| x.exp(),
+            ),
+            (
+                |x| x.log2_fast_approx_checked::<P3>(),
+                |x| x.log2_fast_approx_checked::<P3>(),
+                |x| x.log2(),
+            ),
+            (
+                |x| x.sqrt_fast_approx_checked::<P3>(),
+                |x| x.sqrt_fast_approx_checked::<P3>(),
+                |x| x.sqrt(),
+            ),
+            (
+                |x| x.powi_const_checked::<5>(),
+                |x| x.powi_const_checked::<5>(),
+                |x| x.powi(5),
+            ),
+            (
+                |x| x.asin_fast_approx_checked::<P3>(),
+                |x| x.asin_fast_approx_checked::<P3>(),
+                |x| x.asin(),
+            ),
+        ];
+        for (simd, scalar, exact) in functions {
+            let result = simd(x);
+            for i in 0..8 {
+                let expected = exact(x[i]);
+                assert!(approx_close(expected, scalar(x[i])), "x: {}", x[i]);
+                assert!(approx_close(expected, result[i]), "x: {}", x[i]);
+            }
+        }
+        let (y, x) = (x, x.reverse());
+        let result = y.atan2_fast_approx_checked::<P3>(x);
+        for i in 0..8 {
+            let expected = y[i].atan2(x[i]);
+            assert!(approx_close(
+                expected,
+                y[i].atan2_fast_approx_checked::<P3>(x[i])
+            ));
+            assert!(approx_close(expected, result[i]));
+        }
+    }
+}
+
+#[inline(never)]
+#[test]
+pub fn wide_trig_error() {
+    // about the same as the bounds in scalar_error, because the reduction shouldn't add any
+    // error. the rounded multiple can leave the remainder slightly past ±PI/2, where the
+    // polynomials are a little less precise.
+    const MAX_ERROR: [f32; 4] = [2.9e-2_f32, 6.1e-4_f32, 7.0e-6_f32, 2.7e-7_f32];
+    const MAX_F64_ERROR_7: f64 = 6.0e-16_f64;
+
+    let mut rng = thread_rng();
+    for _i in 0..ITERS {
+        // random bits cover every exponent, so both the Cody-Waite and Payne-Hanek reductions
+        // are tested
+        let x =
+            f32::from_bits(rng.gen_range(0..f32::INFINITY.to_bits()) | (rng.gen::<u32>() << 31));
+
+        let approx = unsafe {
+            [
+                x.sincos_wide_fast_approx::<P0>(),
+                x.sincos_wide_fast_approx::<P1>(),
+                x.sincos_wide_fast_approx::<P2>(),
+                x.sincos_wide_fast_approx::<P3>(),
+            ]
+        };
+        let (sin_exact, cos_exact) = (x as f64).sin_cos();
+        let (sin_exact, cos_exact) = (sin_exact as f32, cos_exact as f32);
+
+        for ((sin_approx, cos_approx), max_error) in approx.into_iter().zip(MAX_ERROR) {
+            assert!(
+                (sin_exact - sin_approx).abs() <= max_error,
+                "Error greater than set maximum: true: {sin_exact}, approx: {sin_approx}, x: {x}"
+            );
+            assert!(
+                (cos_exact - cos_approx).abs() <= max_error,
+                "Error greater than set maximum: true: {cos_exact}, approx: {cos_approx}, x: {x}"
+            );
+        }
+        assert_eq!(approx[3].0, unsafe { x.sin_wide_fast_approx::<P3>() });
+        assert_eq!(approx[3].1, unsafe { x.cos_wide_fast_approx::<P3>() });
+
+        let x =
+            f64::from_bits(rng.gen_range(0..f64::INFINITY.to_bits()) | (rng.gen::<u64>() << 63));
+
+        let (sin_approx, cos_approx) = unsafe { x.sincos_wide_fast_approx::<P7>() };
+        let (sin_exact, cos_exact) = x.sin_cos();
+
+        assert!(
+            (sin_exact - sin_approx).abs() <= MAX_F64_ERROR_7,
+            "Error greater than set maximum: true: {sin_exact}, approx: {sin_approx}, x: {x}"
+        );
+        assert!(
+            (cos_exact - cos_approx).abs() <= MAX_F64_ERROR_7,
+            "Error greater than set maximum: true: {cos_exact}, approx: {cos_approx}, x: {x}"
+        );
+    }
+
+    assert!(f32::INFINITY.sin_wide_fast_approx_checked::<P3>().is_nan());
+    assert!(f64::NAN.cos_wide_fast_approx_checked::<P7>().is_nan());
+}
+
+#[inline(never)]
+#[test]
+pub fn precision_max_error() {
+    fn check<P: Precision<f32> + Precision<f64>>(rng: &mut ThreadRng) {
+        for _i in 0..ITERS {
+            let x = rng.gen_range(-FRAC_PI_2..FRAC_PI_2);
+            let (sin_approx, cos_approx) =
+                unsafe { (x.sin_fast_approx::<P>(), x.cos_fast_approx::<P>()) };
+            assert!((x.sin() - sin_approx).abs() <= <P as Precision<f32>>::SIN_MAX_ERROR);
+            assert!((x.cos() - cos_approx).abs() <= <P as Precision<f32>>::SIN_MAX_ERROR);
+
+            let x = x as f64;
+            let (sin_approx, cos_approx) =
+                unsafe { (x.sin_fast_approx::<P>(), x.cos_fast_approx::<P>()) };
+            assert!((x.sin() - sin_approx).abs() <= <P as Precision<f64>>::SIN_MAX_ERROR);
+            assert!((x.cos() - cos_approx).abs() <= <P as Precision<f64>>::SIN_MAX_ERROR);
+        }
+    }
+
+    let mut rng = thread_rng();
+    check::<Fastest>(&mut rng);
+    check::<P1>(&mut rng);
+    check::<P2>(&mut rng);
+    check::<Accurate>(&mut rng);
+
+    for _i in 0..ITERS {
+        let x = rng.gen_range(-core::f64::consts::FRAC_PI_2..core::f64::consts::FRAC_PI_2);
+        let exact = x.sin();
+        unsafe {
+            assert!((exact - x.sin_fast_approx::<P4>()).abs() <= P4::SIN_MAX_ERROR);
+            assert!((exact - x.sin_fast_approx::<P5>()).abs() <= P5::SIN_MAX_ERROR);
+            assert!((exact - x.sin_fast_approx::<P6>()).abs() <= P6::SIN_MAX_ERROR);
+            assert!((exact - x.sin_fast_approx::<P7>()).abs() <= P7::SIN_MAX_ERROR);
+        }
+    }
+
+    // the f64 errors that are documented for atan, asin, acos and tanh, with some room for the
+    // error of the std functions at the highest levels
+    fn check_f64<P: Precision<f64>>(rng: &mut ThreadRng, [atan, acos, tanh]: [f64; 3]) {
+        let close = |approx: f64, exact: f64, error: f64| (approx - exact).abs() <= error * 1.25;
+        for _i in 0..ITERS {
+            let x = rng.gen_range(-1.0..1.0_f64);
+            let y = rng.gen_range(-10.0..10.0_f64);
+            unsafe {
+                assert!(close((x * 4.0).atan_fast_approx::<P>(), (x * 4.0).atan(), atan));
+                assert!(close((1.0 / x).atan_fast_approx::<P>(), (1.0 / x).atan(), atan));
+                assert!(close(y.atan2_fast_approx::<P>(x), y.atan2(x), atan));
+                assert!(close(x.asin_fast_approx::<P>(), x.asin(), acos));
+                assert!(close(x.acos_fast_approx::<P>(), x.acos(), acos));
+                assert!(close((y * 2.0).tanh_fast_approx::<P>(), (y * 2.0).tanh(), tanh));
+            }
+        }
+    }
+
+    check_f64::<P0>(&mut rng, [8.1e-5, 3.8e-5, 8.6e-4]);
+    check_f64::<P1>(&mut rng, [1.1e-5, 4.8e-6, 3.7e-5]);
+    check_f64::<P2>(&mut rng, [1.7e-6, 6.4e-7, 1.3e-6]);
+    check_f64::<P3>(&mut rng, [2.5e-7, 8.8e-8, 3.7e-8]);
+    check_f64::<P4>(&mut rng, [8.9e-10, 2.7e-10, 2.0e-11]);
+    check_f64::<P5>(&mut rng, [3.5e-12, 6.0e-12, 3.9e-13]);
+    check_f64::<P6>(&mut rng, [9.1e-14, 2.3e-14, 6.9e-15]);
+    check_f64::<P7>(&mut rng, [4.4e-16, 8.9e-16, 4.4e-16]);
+}
+
+#[test]
+pub fn batch() {
+    // with runtime dispatch, every level that the CPU supports is checked, not only the best one
+    #[cfg(all(
+        feature = "runtime-dispatch",
+        any(target_arch = "x86", target_arch = "x86_64")
+    ))]
+    {
+        use crate::batch::dispatch::{set_level, Level};
+
+        for level in Level::ALL {
+            if level.is_supported() {
+                set_level(level);
+                check_batch();
+            }
+        }
+    }
+    #[cfg(not(all(
+        feature = "runtime-dispatch",
+        any(target_arch = "x86", target_arch = "x86_64")
+    )))]
+    check_batch();
+}
+
+fn check_batch() {
+    let mut rng = thread_rng();
+    // the offset start and odd length give every slice an unaligned head and a partial remainder
+    let values: Vec<f32> = (0..71).map(|_| rng.gen_range(-10.0..10.0)).collect();
+    let input = &values[1..70];
+    let second_input: Vec<f32> = input.iter().map(|x| x * 0.5 + 20.0).collect();
+
+    let mut output = vec![0.0; input.len()];
+    let mut cos_output = vec![0.0; input.len()];
+    unsafe {
+        crate::batch::f32::sin_fast_approx::<P3>(input, &mut output);
+    }
+    for (&x, &result) in input.iter().zip(&output) {
+        assert!((result - unsafe { x.sin_fast_approx::<P3>() }).abs() < 1.0e-6);
+    }
+
+    let mut in_place = input.to_vec();
+    unsafe {
+        crate::batch::f32::sin_fast_approx_in_place::<P3>(&mut in_place[..]);
+    }
+    assert_eq!(in_place, output);
+
+    unsafe {
+        crate::batch::f32::sincos_fast_approx::<P3>(input, &mut output, &mut cos_output);
+    }
+    for ((&x, &sin), &cos) in input.iter().zip(&output).zip(&cos_output) {
+        let (expected_sin, expected_cos) = unsafe { x.sincos_fast_approx::<P3>() };
+        assert!((sin - expected_sin).abs() < 1.0e-6);
+        assert!((cos - expected_cos).abs() < 1.0e-6);
+    }
+
+    let bases: Vec<f32> = input.iter().map(|x| x.abs() + 2.0).collect();
+    unsafe {
+        crate::batch::f32::log_fast_approx::<P3>(&second_input, &bases, &mut output);
+    }
+    for ((&x, &base), &result) in second_input.iter().zip(&bases).zip(&output) {
+        let expected = unsafe { x.log_fast_approx::<P3>(base) };
+        assert!((result - expected).abs() <= expected.abs() * 1.0e-5);
+    }
+
+    let mut checked_input = input.to_vec();
+    checked_input[0] = f32::NAN;
+    checked_input[68] = f32::INFINITY;
+    crate::batch::f32::sin_wide_fast_approx_checked::<P3>(&checked_input, &mut output);
+    assert!(output[0].is_nan());
+    assert!(output[68].is_nan());
+    for (&x, &result) in checked_input[1..68].iter().zip(&output[1..68]) {
+        assert!((result - x.sin_wide_fast_approx_checked::<P3>()).abs() < 1.0e-6);
+    }
+
+    let mut f64_output = vec![0.0; input.len()];
+    let f64_input: Vec<f64> = input.iter().map(|&x| x as f64).collect();
+    unsafe {
+        crate::batch::f64::exp_fast_approx::<P7>(&f64_input, &mut f64_output);
+    }
+    for (&x, &result) in f64_input.iter().zip(&f64_output) {
+        let expected = unsafe { x.exp_fast_approx::<P7>() };
+        assert!((result - expected).abs() <= expected * 1.0e-14);
+    }
+
+    let ints: Vec<u32> = (0..71)
+        .map(|_| rng.next_u32() >> rng.gen_range(0..32))
+        .collect();
+    let ints = &ints[1..70];
+    let mut int_output = vec![0; ints.len()];
+    crate::batch::u32::isqrt(ints, &mut int_output);
+    for (&x, &result) in ints.iter().zip(&int_output) {
+        assert_eq!(result, x.isqrt());
+    }
+
+    let mut checked_output = vec![None; ints.len()];
+    let mut checked_ints = ints.to_vec();
+    checked_ints[3] = 0;
+    crate::batch::u32::checked_ilog_const_base::<10>(&checked_ints, &mut checked_output);
+    assert_eq!(checked_output[3], None);
+    for (&x, &result) in checked_ints.iter().zip(&checked_output) {
+        assert_eq!(result, x.checked_ilog_const_base::<10>());
+    }
+
+    let wide_ints: Vec<u128> = ints.iter().map(|&x| x as u128).collect();
+    let exponents: Vec<u128> = ints.iter().map(|&x| (x % 5) as u128).collect();
+    let mut wide_output = wide_ints.clone();
+    crate::batch::u128::wrapping_ipow_in_place(&mut wide_output, &exponents);
+    for ((&x, &exp), &result) in wide_ints.iter().zip(&exponents).zip(&wide_output) {
+        assert_eq!(result, x.wrapping_ipow(exp));
+    }
+}
+
+#[cfg(all(
+    feature = "avx512",
+    target_feature = "avx512f",
+    target_feature = "avx512cd"
+))]
+#[test]
+pub fn avx512() {
+    let mut rng = thread_rng();
+
+    for _i in 0..ITERS {
+        // the 16-lane vectors take the AVX-512 paths, and the 8-lane halves take the generic paths
+        let x = f32x16::from_array(array::from_fn(|_| rng.gen_range(f32::MIN_POSITIVE..1.0e30)));
+        let (low, high) = (f32x8::from_slice(&x[..8]), f32x8::from_slice(&x[8..]));
+        let result = unsafe { x.log2_fast_approx::<P3>() };
+        assert_eq!(result[..8], unsafe { low.log2_fast_approx::<P3>() }[..]);
+        assert_eq!(result[8..], unsafe { high.log2_fast_approx::<P3>() }[..]);
+
+        let ints = u32x16::from_array(array::from_fn(|_| rng.next_u32() >> rng.gen_range(0..32)));
+        let wide_ints =
+            u64x8::from_array(array::from_fn(|_| rng.next_u64() >> rng.gen_range(0..64)));
+        let (result, valid) = ints.checked_ilog_const_base::<2>();
+        let (wide_result, wide_valid) = wide_ints.checked_ilog_const_base::<2>();
+        for i in 0..16 {
+            assert_eq!(valid.test(i), ints[i] != 0);
+            if ints[i] != 0 {
+                assert_eq!(result[i], ints[i].ilog2());
+            }
+        }
+        for i in 0..8 {
+            assert_eq!(wide_valid.test(i), wide_ints[i] != 0);
+            if wide_ints[i] != 0 {
+                assert_eq!(wide_result[i], wide_ints[i].ilog2() as u64);
+            }
+        }
+    }
+
+    // the checked versions replace the special cases with masked moves instead of a fallback
+    let special = f32x16::from_array([
+        0.0,
+        -0.0,
+        -1.0,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        f32::NAN,
+        1.0e-40,
+        f32::MIN_POSITIVE,
+        f32::MAX,
+        1.0,
+        2.0,
+        0.5,
+        3.0,
+        1.0e-45,
+        -1.0e-40,
+        7.0,
+    ]);
+    let result = special.log2_fast_approx_checked::<P3>();
+    let ln_result = special.ln_fast_approx_checked::<P3>();
+    for i in 0..16 {
+        let expected = special[i].log2();
+        if expected.is_nan() {
+            assert!(result[i].is_nan() && ln_result[i].is_nan());
+        } else if expected.is_infinite() {
+            assert_eq!(result[i], expected);
+            assert_eq!(ln_result[i], special[i].ln());
+        } else {
+            // the log2 approximation has an absolute error of about 1.3e-5 near 1
+            assert!((result[i] - expected).abs() <= 1.0e-4 * expected.abs().max(1.0));
+            assert!((ln_result[i] - special[i].ln()).abs() <= 1.0e-4 * expected.abs().max(1.0));
+        }
+    }
+}
+
+#[cfg(feature = "deterministic")]
+#[test]
+pub fn deterministic() {
+    // the inputs come from a fixed sequence instead of `thread_rng`, so that the hash of the
+    // results can be compared against the one from any other target
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = move || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (state >> 11) as f64 / (1_u64 << 53) as f64
+    };
+    let mut hash = 0_u64;
+    let mut add = |bits: u64| hash = (hash ^ bits).wrapping_mul(0x0000_0100_0000_01b3);
+
+    for _i in 0..ITERS {
+        let x = (next() * 2.0 - 1.0) * 100.0;
+        let y = next() * 20.0 - 10.0;
+        let single = x as f32;
+
+        unsafe {
+            add(single.sin_wide_fast_approx::<P3>().to_bits() as u64);
+            add(single.exp_fast_approx::<P3>().to_bits() as u64);
+            add(single.abs().ln_fast_approx::<P3>().to_bits() as u64);
+            add(single.atan2_fast_approx::<P3>(y as f32).to_bits() as u64);
+            add(x.sin_wide_fast_approx::<P7>().to_bits());
+            add(x.exp_fast_approx::<P7>().to_bits());
+            add(x.abs().ln_fast_approx::<P7>().to_bits());
+            add(x.atan2_fast_approx::<P7>(y).to_bits());
+        }
+        add(single.exp_fast_approx_checked::<P3>().to_bits() as u64);
+        add(single.pow_fast_approx_checked::<P3>(y as f32).to_bits() as u64);
+        add(single.sinh_fast_approx_checked::<P3>().to_bits() as u64);
+        add((x * 10.0).exp_fast_approx_checked::<P7>().to_bits());
+        add(x.pow_fast_approx_checked::<P7>(y).to_bits());
+        add(x.cbrt_fast_approx_checked::<P7>().to_bits());
+    }
+    assert_eq!(hash, 17_884_611_883_380_735_711);
+
+    // the slow paths of the checked functions are approximations too, so the special cases that
+    // libm would handle are checked here
+    let special = [
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        2.5,
+        -3.0,
+        1.0e-40,
+        -150.0,
+        -140.5,
+        128.5,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        f32::NAN,
+    ];
+    // the log2 approximation has an absolute error of about 1.3e-5 near 1, which pow multiplies
+    // by the exponent
+    let close = |a: f32, b: f32, scale: f32| {
+        (a.is_nan() && b.is_nan())
+            || a == b
+            || (a - b).abs() <= 1.0e-4 * scale.max(1.0) * b.abs().max(1.0)
+    };
+    for &x in &special {
+        assert!(close(x.log2_fast_approx_checked::<P3>(), x.log2(), 1.0));
+        assert!(close(x.exp2_fast_approx_checked::<P3>(), x.exp2(), 1.0));
+        assert!(close(x.exp_fast_approx_checked::<P3>(), x.exp(), 1.0));
+        for &y in &special {
+            assert!(close(
+                x.pow_fast_approx_checked::<P3>(y),
+                x.powf(y),
+                y.abs()
+            ));
+        }
+    }
+}
+
 // #[derive(Clone, Copy, Default)]
 // struct Color(u32, u32, u32);
 //
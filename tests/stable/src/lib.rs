@@ -0,0 +1,205 @@
+// Tests for the stable backend. The full test suite uses `core::simd`, so it needs the `nightly`
+// feature, and these check the scalar and array impls against `std` on stable Rust.
+#![cfg(test)]
+
+use core::array;
+use core::f32::consts::FRAC_PI_2;
+use core::ops::Range;
+use fath::*;
+use rand::{thread_rng, Rng, RngCore};
+
+const ITERS: usize = 1 << 16;
+
+#[test]
+fn scalar_error() {
+    const SIN_RANGE: Range<f32> = -FRAC_PI_2..FRAC_PI_2;
+    const WIDE_RANGE: Range<f32> = -1.0e4..1.0e4;
+    const EXP2_RANGE: Range<f64> = -1021.0..1023.0;
+    const SIN_MAX_ERROR_3: f32 = 2.7e-7_f32;
+    const WIDE_MAX_ERROR_3: f32 = 4.0e-6_f32;
+    const ATAN_MAX_ERROR_3: f32 = 6.5e-7_f32;
+    const ACOS_MAX_ERROR_3: f32 = 5.5e-7_f32;
+    const EXP2_MAX_ERROR_7: f64 = 4.5e-16_f64;
+    const SQRT_MAX_ERROR_3: f32 = 3.0e-7_f32;
+
+    let mut rng = thread_rng();
+    for _i in 0..ITERS {
+        let x = rng.gen_range(SIN_RANGE);
+        let approx = unsafe { x.sin_fast_approx::<P3>() };
+        let exact = x.sin();
+        assert!(
+            (exact - approx).abs() <= SIN_MAX_ERROR_3,
+            "Error greater than set maximum: true: {exact}, approx: {approx}, x: {x}"
+        );
+
+        // the range reduction rounds to the nearest multiple of pi
+        let x = rng.gen_range(WIDE_RANGE);
+        let approx = unsafe { x.cos_wide_fast_approx::<P3>() };
+        let exact = (x as f64).cos() as f32;
+        assert!(
+            (exact - approx).abs() <= WIDE_MAX_ERROR_3,
+            "Error greater than set maximum: true: {exact}, approx: {approx}, x: {x}"
+        );
+
+        // acos and asin use a square root
+        let y = rng.gen_range(-1.0..1.0_f32);
+        let x = rng.gen_range(-1.0..1.0_f32);
+        let approx = unsafe { y.atan2_fast_approx::<P3>(x) };
+        let exact = y.atan2(x);
+        assert!(
+            (exact - approx).abs() <= ATAN_MAX_ERROR_3,
+            "Error greater than set maximum: true: {exact}, approx: {approx}, y: {y}, x: {x}"
+        );
+        let approx = unsafe { x.acos_fast_approx::<P3>() };
+        let exact = x.acos();
+        assert!(
+            (exact - approx).abs() <= ACOS_MAX_ERROR_3,
+            "Error greater than set maximum: true: {exact}, approx: {approx}, x: {x}"
+        );
+
+        let x = rng.gen_range(EXP2_RANGE);
+        let approx = unsafe { x.exp2_fast_approx::<P7>() };
+        let exact = x.exp2();
+        assert!(
+            ((exact - approx) / exact).abs() <= EXP2_MAX_ERROR_7,
+            "Error greater than set maximum: true: {exact}, approx: {approx}, x: {x}"
+        );
+
+        let x = rng.gen_range(1.0e-30..1.0e30_f32);
+        let approx = unsafe { x.sqrt_fast_approx::<P3>() };
+        let exact = (x as f64).sqrt();
+        assert!(
+            ((exact - approx as f64) / exact).abs() <= SQRT_MAX_ERROR_3 as f64,
+            "Error greater than set maximum: true: {exact}, approx: {approx}, x: {x}"
+        );
+    }
+}
+
+#[test]
+fn checked_float() {
+    assert!(f32::NAN.sin_fast_approx_checked::<P3>().is_nan());
+    assert!((-1.0_f32).ln_fast_approx_checked::<P3>().is_nan());
+    assert!((-1.0_f64).sqrt_fast_approx_checked::<P7>().is_nan());
+    assert_eq!(0.0_f32.ln_fast_approx_checked::<P3>(), f32::NEG_INFINITY);
+    assert_eq!(f32::INFINITY.exp_fast_approx_checked::<P3>(), f32::INFINITY);
+    assert_eq!(
+        1000.0_f64.exp2_fast_approx_checked::<P7>(),
+        2.0_f64.powi(1000)
+    );
+    assert_eq!((-0.0_f32).sqrt_fast_approx_checked::<P3>(), 0.0);
+
+    // beyond the bound of the fast range reduction, the wide one is used
+    let x = 3.0e6_f32;
+    let checked = x.sin_fast_approx_checked::<P3>();
+    assert_eq!(checked, unsafe { x.sin_wide_fast_approx::<P3>() });
+    assert!((checked - (x as f64).sin() as f32).abs() <= 4.0e-6);
+
+    let subnormal = f32::from_bits(1);
+    let exact = (subnormal as f64).sqrt();
+    let approx = subnormal.sqrt_fast_approx_checked::<P3>() as f64;
+    assert!(((exact - approx) / exact).abs() <= 3.0e-7);
+}
+
+#[test]
+fn int() {
+    let mut rng = thread_rng();
+    for _i in 0..ITERS {
+        let x = (rng.next_u32() >> rng.gen_range(0..32)).max(1);
+        let exp = rng.gen_range(0..40_u64);
+        assert_eq!(x.ilog_const_base::<10>(), x.ilog10());
+        assert_eq!(x.ilog_var_base(7), x.ilog(7));
+        assert_eq!(FastExactInt::isqrt(x), x.isqrt());
+        assert_eq!(x.div_const::<7>(), x / 7);
+        assert_eq!(x.rem_const::<7>(), x % 7);
+        assert_eq!(
+            exp.checked_exp_const_coeff::<3>(),
+            3_u64.checked_pow(exp as u32)
+        );
+
+        let d = rng.next_u64() >> rng.gen_range(0..64);
+        let x_64 = rng.next_u64();
+        assert_eq!(x_64 / FastDivisor::<u64>::new(d.max(1)), x_64 / d.max(1));
+        assert_eq!(x_64 % FastDivisor::<u64>::new(d.max(1)), x_64 % d.max(1));
+    }
+
+    assert_eq!(0_i32.checked_ilog_const_base::<2>(), None);
+    assert_eq!((-2_i64).checked_ipow(3), Some(-8));
+    assert_eq!(5_i32.exp_const_signed_coeff::<-2>(), -32);
+}
+
+#[test]
+fn arrays() {
+    let mut rng = thread_rng();
+    for _i in 0..ITERS / 8 {
+        let x = [0.0_f64; 8].map(|_| rng.gen_range(-10.0..10.0));
+        let y = [0.0_f64; 8].map(|_| rng.gen_range(0.5..4.0));
+
+        // nothing is contracted into FMAs on stable, so the lanes are exactly equal
+        unsafe {
+            assert_eq!(
+                x.sin_wide_fast_approx::<P7>(),
+                x.map(|x| x.sin_wide_fast_approx::<P7>())
+            );
+            assert_eq!(
+                y.pow_fast_approx::<P5>(x),
+                array::from_fn(|i| y[i].pow_fast_approx::<P5>(x[i]))
+            );
+        }
+        assert_eq!(
+            x.tanh_fast_approx_checked::<P7>(),
+            x.map(|x| x.tanh_fast_approx_checked::<P7>())
+        );
+
+        let x_int = [0_u16; 8].map(|_| rng.gen_range(1..u16::MAX));
+        let exp = [0_u16; 8].map(|_| rng.gen_range(0..4));
+        assert_eq!(x_int.icbrt(), x_int.map(|x| x.icbrt()));
+        assert_eq!(x_int / FastDivisor::<u16>::new(13), x_int.map(|x| x / 13));
+        let (result, valid) = x_int.checked_ipow(exp);
+        for i in 0..8 {
+            assert_eq!(
+                valid[i].then_some(result[i]),
+                x_int[i].checked_pow(exp[i] as u32)
+            );
+        }
+    }
+}
+
+#[cfg(feature = "deterministic")]
+#[test]
+fn deterministic() {
+    // the same inputs and hash as the test of the `nightly` backend, so both backends have to give
+    // bit-identical results
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = move || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (state >> 11) as f64 / (1_u64 << 53) as f64
+    };
+    let mut hash = 0_u64;
+    let mut add = |bits: u64| hash = (hash ^ bits).wrapping_mul(0x0000_0100_0000_01b3);
+
+    for _i in 0..ITERS {
+        let x = (next() * 2.0 - 1.0) * 100.0;
+        let y = next() * 20.0 - 10.0;
+        let single = x as f32;
+
+        unsafe {
+            add(single.sin_wide_fast_approx::<P3>().to_bits() as u64);
+            add(single.exp_fast_approx::<P3>().to_bits() as u64);
+            add(single.abs().ln_fast_approx::<P3>().to_bits() as u64);
+            add(single.atan2_fast_approx::<P3>(y as f32).to_bits() as u64);
+            add(x.sin_wide_fast_approx::<P7>().to_bits());
+            add(x.exp_fast_approx::<P7>().to_bits());
+            add(x.abs().ln_fast_approx::<P7>().to_bits());
+            add(x.atan2_fast_approx::<P7>(y).to_bits());
+        }
+        add(single.exp_fast_approx_checked::<P3>().to_bits() as u64);
+        add(single.pow_fast_approx_checked::<P3>(y as f32).to_bits() as u64);
+        add(single.sinh_fast_approx_checked::<P3>().to_bits() as u64);
+        add((x * 10.0).exp_fast_approx_checked::<P7>().to_bits());
+        add(x.pow_fast_approx_checked::<P7>(y).to_bits());
+        add(x.cbrt_fast_approx_checked::<P7>().to_bits());
+    }
+    assert_eq!(hash, 17_884_611_883_380_735_711);
+}